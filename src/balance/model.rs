@@ -0,0 +1,97 @@
+use crate::config::ScheduleConfig;
+use crate::holidays::holiday_calendar::HolidayCalendar;
+use chrono::{Datelike, Duration, NaiveDate};
+
+pub struct WorkingTimeModel {
+    schedules: Vec<ScheduleConfig>,
+    holidays: HolidayCalendar,
+}
+
+impl WorkingTimeModel {
+    pub fn new(schedules: &[ScheduleConfig]) -> Self {
+        let mut schedules = schedules.to_vec();
+        schedules.sort_by_key(|schedule| schedule.from);
+        Self {
+            schedules,
+            holidays: HolidayCalendar::new(),
+        }
+    }
+
+    pub fn with_holidays(mut self, holidays: HolidayCalendar) -> Self {
+        self.holidays = holidays;
+        self
+    }
+
+    pub fn start(&self) -> Option<NaiveDate> {
+        self.schedules.first().and_then(|schedule| schedule.from)
+    }
+
+    pub fn expected(&self, date: NaiveDate) -> Duration {
+        if self.holidays.is_holiday(date) {
+            return Duration::zero();
+        }
+        self.schedules
+            .iter()
+            .rev()
+            .find(|schedule| schedule.from.is_none_or(|from| from <= date))
+            .map(|schedule| schedule.hours_on(date.weekday()))
+            .unwrap_or_else(Duration::zero)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_expects_hours_of_the_schedule_valid_on_a_day() {
+        let model = WorkingTimeModel::new(&[
+            ScheduleConfig {
+                from: Some(NaiveDate::from_ymd(2020, 7, 1)),
+                monday: 4.0,
+                ..ScheduleConfig::default()
+            },
+            ScheduleConfig {
+                from: Some(NaiveDate::from_ymd(2020, 1, 6)),
+                monday: 8.0,
+                ..ScheduleConfig::default()
+            },
+        ]);
+
+        assert_eq!(model.start(), Some(NaiveDate::from_ymd(2020, 1, 6)));
+        assert_eq!(
+            model.expected(NaiveDate::from_ymd(2019, 12, 30)),
+            Duration::zero()
+        );
+        assert_eq!(
+            model.expected(NaiveDate::from_ymd(2020, 1, 13)),
+            Duration::hours(8)
+        );
+        assert_eq!(
+            model.expected(NaiveDate::from_ymd(2020, 1, 14)),
+            Duration::zero()
+        );
+        assert_eq!(
+            model.expected(NaiveDate::from_ymd(2020, 7, 6)),
+            Duration::hours(4)
+        );
+    }
+
+    #[test]
+    fn it_expects_no_hours_on_holidays() {
+        let model = WorkingTimeModel::new(&[ScheduleConfig {
+            monday: 8.0,
+            ..ScheduleConfig::default()
+        }])
+        .with_holidays(HolidayCalendar::new().with_state("BE".parse().unwrap()));
+
+        assert_eq!(
+            model.expected(NaiveDate::from_ymd(2020, 4, 13)),
+            Duration::zero()
+        );
+        assert_eq!(
+            model.expected(NaiveDate::from_ymd(2020, 4, 20)),
+            Duration::hours(8)
+        );
+    }
+}
@@ -0,0 +1,151 @@
+use crate::balance::model::WorkingTimeModel;
+use crate::rules::format_duration;
+use crate::validation::activity::Activity;
+use chrono::{Datelike, Duration, NaiveDate};
+use std::collections::BTreeMap;
+use std::fmt;
+
+#[derive(Debug, PartialEq)]
+pub struct Period {
+    pub name: String,
+    pub actual: Duration,
+    pub expected: Duration,
+}
+
+impl Period {
+    fn new(name: String) -> Self {
+        Self {
+            name,
+            actual: Duration::zero(),
+            expected: Duration::zero(),
+        }
+    }
+
+    pub fn overtime(&self) -> Duration {
+        self.actual - self.expected
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Report {
+    weeks: Vec<Period>,
+    months: Vec<Period>,
+}
+
+impl Report {
+    pub fn new(
+        activities: &[Activity],
+        model: &WorkingTimeModel,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Self {
+        let mut actual: BTreeMap<NaiveDate, Duration> = BTreeMap::new();
+        for activity in activities {
+            let day = actual
+                .entry(activity.start().date().date().naive_local())
+                .or_insert_with(Duration::zero);
+            *day = *day + (*activity.end().date() - *activity.start().date());
+        }
+
+        let mut weeks: BTreeMap<String, Period> = BTreeMap::new();
+        let mut months: BTreeMap<String, Period> = BTreeMap::new();
+        let mut date = from;
+        while date <= to {
+            let week = date.iso_week();
+            let week = format!("{}-W{:02}", week.year(), week.week());
+            let month = date.format("%Y-%m").to_string();
+            for period in [
+                weeks
+                    .entry(week.clone())
+                    .or_insert_with(|| Period::new(week)),
+                months
+                    .entry(month.clone())
+                    .or_insert_with(|| Period::new(month)),
+            ] {
+                period.actual = period.actual + *actual.get(&date).unwrap_or(&Duration::zero());
+                period.expected = period.expected + model.expected(date);
+            }
+            date = date.succ();
+        }
+
+        Self {
+            weeks: weeks.into_values().collect(),
+            months: months.into_values().collect(),
+        }
+    }
+
+    pub fn balance(&self) -> Duration {
+        self.months.iter().fold(Duration::zero(), |balance, month| {
+            balance + month.overtime()
+        })
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (title, periods) in [("Week", &self.weeks), ("Month", &self.months)] {
+            writeln!(
+                f,
+                "{:<10} | {:>8} | {:>8} | {:>8} | {:>8}",
+                title, "Actual", "Expected", "Overtime", "Balance"
+            )?;
+            let mut balance = Duration::zero();
+            for period in periods {
+                balance = balance + period.overtime();
+                writeln!(
+                    f,
+                    "{:<10} | {:>8} | {:>8} | {:>8} | {:>8}",
+                    period.name,
+                    format_duration(period.actual),
+                    format_duration(period.expected),
+                    format_duration(period.overtime()),
+                    format_duration(balance)
+                )?;
+            }
+            writeln!(f)?;
+        }
+        write!(f, "Balance: {} hours", format_duration(self.balance()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::ScheduleConfig;
+    use crate::test_fixtures::{time, ActivityBuilder};
+
+    fn activity(month: u32, day: u32, hours: u32) -> Activity {
+        ActivityBuilder::new()
+            .with_times(time(month, day, 8, 0), time(month, day, 8 + hours, 0))
+            .build()
+    }
+
+    #[test]
+    fn it_sums_overtime_per_week_and_month() {
+        let model = WorkingTimeModel::new(&[ScheduleConfig {
+            monday: 8.0,
+            tuesday: 8.0,
+            ..ScheduleConfig::default()
+        }]);
+
+        let report = Report::new(
+            &[activity(1, 27, 9), activity(1, 28, 8), activity(2, 3, 6)],
+            &model,
+            NaiveDate::from_ymd(2020, 1, 27),
+            NaiveDate::from_ymd(2020, 2, 4),
+        );
+
+        assert_eq!(
+            report.to_string(),
+            "Week       |   Actual | Expected | Overtime |  Balance
+2020-W05   |    17:00 |    16:00 |     1:00 |     1:00
+2020-W06   |     6:00 |    16:00 |   -10:00 |    -9:00
+
+Month      |   Actual | Expected | Overtime |  Balance
+2020-01    |    17:00 |    16:00 |     1:00 |     1:00
+2020-02    |     6:00 |    16:00 |   -10:00 |    -9:00
+
+Balance: -9:00 hours"
+        );
+    }
+}
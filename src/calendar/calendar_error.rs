@@ -0,0 +1,34 @@
+use std::fmt;
+
+#[derive(Debug, PartialEq)]
+pub enum CalendarError {
+    InvalidTime(String),
+    InvalidDuration(String),
+    InvalidPattern(String, String),
+    UnsupportedRecurrence(String, String),
+    UnknownTimeZone(String),
+}
+
+impl fmt::Display for CalendarError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self {
+            Self::InvalidTime(time) => write!(f, "CalendarError: Invalid time '{}'", time),
+            Self::InvalidDuration(duration) => {
+                write!(f, "CalendarError: Invalid duration '{}'", duration)
+            }
+            Self::InvalidPattern(pattern, error) => {
+                write!(
+                    f,
+                    "CalendarError: Invalid title pattern '{}': {}",
+                    pattern, error
+                )
+            }
+            Self::UnsupportedRecurrence(summary, rule) => write!(
+                f,
+                "CalendarError: Cannot expand recurrence '{}' of '{}'",
+                rule, summary
+            ),
+            Self::UnknownTimeZone(zone) => write!(f, "CalendarError: Unknown time zone '{}'", zone),
+        }
+    }
+}
@@ -0,0 +1,144 @@
+#[derive(Debug, PartialEq, Default)]
+pub struct Property {
+    pub name: String,
+    pub parameters: String,
+    pub value: String,
+}
+
+#[derive(Debug, PartialEq, Default)]
+pub struct Component {
+    pub name: String,
+    pub properties: Vec<Property>,
+    pub components: Vec<Component>,
+}
+
+impl Component {
+    pub fn parse(text: &str) -> Self {
+        let mut stack = vec![Component::default()];
+        for line in unfold(text) {
+            let property = property(&line);
+            match property.name.as_str() {
+                "BEGIN" => stack.push(Component {
+                    name: property.value.to_uppercase(),
+                    ..Component::default()
+                }),
+                "END" if stack.len() > 1 => {
+                    let component = stack.pop().unwrap();
+                    stack.last_mut().unwrap().components.push(component);
+                }
+                "END" => {}
+                _ => stack.last_mut().unwrap().properties.push(property),
+            }
+        }
+        while stack.len() > 1 {
+            let component = stack.pop().unwrap();
+            stack.last_mut().unwrap().components.push(component);
+        }
+        stack.pop().unwrap()
+    }
+
+    pub fn all(&self, name: &str) -> Vec<&Component> {
+        let mut found = vec![];
+        for component in &self.components {
+            if component.name == name {
+                found.push(component);
+            } else {
+                found.extend(component.all(name));
+            }
+        }
+        found
+    }
+
+    pub fn property(&self, name: &str) -> Option<&Property> {
+        self.properties
+            .iter()
+            .find(|property| property.name == name)
+    }
+
+    pub fn properties_named(&self, name: &str) -> impl Iterator<Item = &Property> {
+        let name = name.to_string();
+        self.properties
+            .iter()
+            .filter(move |property| property.name == name)
+    }
+}
+
+impl Property {
+    pub fn parameter(&self, name: &str) -> Option<&str> {
+        self.parameters.split(';').find_map(|parameter| {
+            let (key, value) = parameter.split_once('=')?;
+            match key.eq_ignore_ascii_case(name) {
+                true => Some(value.trim_matches('"')),
+                false => None,
+            }
+        })
+    }
+}
+
+fn unfold(text: &str) -> Vec<String> {
+    let mut lines: Vec<String> = vec![];
+    for line in text.lines() {
+        let line = line.trim_end_matches('\r');
+        match (
+            line.strip_prefix(' ').or_else(|| line.strip_prefix('\t')),
+            lines.last_mut(),
+        ) {
+            (Some(continuation), Some(last)) => last.push_str(continuation),
+            _ => lines.push(line.to_string()),
+        }
+    }
+    lines
+}
+
+fn property(line: &str) -> Property {
+    let mut quoted = false;
+    let colon = line.char_indices().find(|(_, character)| match character {
+        '"' => {
+            quoted = !quoted;
+            false
+        }
+        ':' => !quoted,
+        _ => false,
+    });
+    let (head, value) = match colon {
+        Some((index, _)) => (&line[..index], &line[index + 1..]),
+        None => (line, ""),
+    };
+    let (name, parameters) = head.split_once(';').unwrap_or((head, ""));
+    Property {
+        name: name.to_uppercase(),
+        parameters: parameters.to_string(),
+        value: value.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_nests_components() {
+        let text = "BEGIN:VCALENDAR\r
+BEGIN:VEVENT\r
+SUMMARY:Standup\r
+BEGIN:VALARM\r
+SUMMARY:Reminder\r
+END:VALARM\r
+END:VEVENT\r
+END:VCALENDAR\r
+";
+
+        let calendar = Component::parse(text);
+        let events = calendar.all("VEVENT");
+
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].property("SUMMARY").unwrap().value, "Standup");
+        assert_eq!(
+            events[0].all("VALARM")[0]
+                .property("SUMMARY")
+                .unwrap()
+                .value,
+            "Reminder"
+        );
+    }
+}
@@ -0,0 +1,115 @@
+use crate::calendar::{calendar_error::CalendarError, event::Event};
+use crate::config::CalendarRule;
+use crate::parser::fields::Fields;
+use regex::Regex;
+
+const UNKNOWN: &str = "?";
+const DATE_TIME_FORMAT: &str = "%Y-%m-%dT%H:%M:%S";
+
+pub fn drafts(events: &[Event], rules: &[CalendarRule]) -> Result<Vec<Fields>, CalendarError> {
+    let patterns = rules
+        .iter()
+        .map(|rule| {
+            Regex::new(&rule.title).map_err(|error| {
+                CalendarError::InvalidPattern(rule.title.clone(), error.to_string())
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(events
+        .iter()
+        .map(|event| {
+            let rule = rules
+                .iter()
+                .zip(&patterns)
+                .find(|(_rule, pattern)| pattern.is_match(&event.summary))
+                .map(|(rule, _pattern)| rule);
+            Fields {
+                start: event.start.format(DATE_TIME_FORMAT).to_string(),
+                end: event.end.format(DATE_TIME_FORMAT).to_string(),
+                project: rule.map_or(UNKNOWN, |rule| &rule.project).to_string(),
+                task: rule.map_or(UNKNOWN, |rule| &rule.task).to_string(),
+                description: rule
+                    .and_then(|rule| rule.description.clone())
+                    .unwrap_or_else(|| event.summary.clone()),
+            }
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::prelude::*;
+
+    fn event(summary: &str) -> Event {
+        Event {
+            start: Local.ymd(2020, 1, 12).and_hms(9, 0, 0),
+            end: Local.ymd(2020, 1, 12).and_hms(9, 15, 0),
+            summary: summary.to_string(),
+        }
+    }
+
+    fn rules() -> Vec<CalendarRule> {
+        vec![
+            CalendarRule {
+                title: "(?i)standup".to_string(),
+                project: "Timeval".to_string(),
+                task: "Meeting".to_string(),
+                description: Some("Daily".to_string()),
+            },
+            CalendarRule {
+                title: "Review".to_string(),
+                project: "Timeval".to_string(),
+                task: "Build".to_string(),
+                description: None,
+            },
+        ]
+    }
+
+    fn fields(project: &str, task: &str, description: &str) -> Fields {
+        Fields {
+            start: "2020-01-12T09:00:00".to_string(),
+            end: "2020-01-12T09:15:00".to_string(),
+            project: project.to_string(),
+            task: task.to_string(),
+            description: description.to_string(),
+        }
+    }
+
+    #[test]
+    fn it_maps_events_with_the_first_matching_rule() {
+        let drafts = drafts(
+            &[
+                event("Daily Standup"),
+                event("Sprint Review"),
+                event("Lunch"),
+            ],
+            &rules(),
+        );
+
+        assert_eq!(
+            drafts,
+            Ok(vec![
+                fields("Timeval", "Meeting", "Daily"),
+                fields("Timeval", "Build", "Sprint Review"),
+                fields("?", "?", "Lunch"),
+            ])
+        );
+    }
+
+    #[test]
+    fn it_reports_invalid_title_patterns() {
+        let rules = vec![CalendarRule {
+            title: "(".to_string(),
+            project: "Timeval".to_string(),
+            task: "Meeting".to_string(),
+            description: None,
+        }];
+
+        assert!(matches!(
+            drafts(&[], &rules),
+            Err(CalendarError::InvalidPattern(_, _))
+        ));
+    }
+}
@@ -0,0 +1,654 @@
+use crate::calendar::{
+    calendar_error::CalendarError, component::Component, recurrence::Recurrence,
+    time_zone::TimeZones,
+};
+use chrono::{DateTime, Duration, Local, NaiveDate, NaiveDateTime, TimeZone, Utc};
+
+const DATE_TIME_FORMAT: &str = "%Y%m%dT%H%M%S";
+const DATE_FORMAT: &str = "%Y%m%d";
+const DATE_LENGTH: usize = 8;
+
+#[derive(Debug, PartialEq)]
+pub struct Event {
+    pub start: DateTime<Local>,
+    pub end: DateTime<Local>,
+    pub summary: String,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct AllDayEvent {
+    start: NaiveDate,
+    days: i64,
+    summary: String,
+    recurrence: Option<Recurrence>,
+    exceptions: Vec<NaiveDate>,
+}
+
+#[derive(Default)]
+struct Properties {
+    uid: String,
+    start: Option<(String, String)>,
+    end: Option<(String, String)>,
+    duration: Option<String>,
+    summary: String,
+    cancelled: bool,
+    recurrence: Option<String>,
+    exceptions: Vec<(String, String)>,
+    recurrence_id: Option<(String, String)>,
+}
+
+pub fn events(
+    text: &str,
+    to: NaiveDate,
+) -> Result<(Vec<Event>, Vec<CalendarError>), CalendarError> {
+    let calendar = Component::parse(text);
+    let zones = TimeZones::parse(&calendar);
+    let vevents = vevents(&calendar);
+    let mut overrides = vec![];
+    for properties in &vevents {
+        if let Some((parameters, value)) = &properties.recurrence_id {
+            if value.len() != DATE_LENGTH {
+                overrides.push((properties.uid.clone(), time(&zones, parameters, value)?));
+            }
+        }
+    }
+
+    let mut events = vec![];
+    let mut errors = vec![];
+    for properties in vevents {
+        let overridden = overrides
+            .iter()
+            .filter(|(uid, _start)| properties.recurrence_id.is_none() && *uid == properties.uid)
+            .map(|(_uid, start)| *start)
+            .collect::<Vec<_>>();
+        match properties.to_events(&zones, to, &overridden) {
+            Err(error @ CalendarError::UnsupportedRecurrence(..)) => errors.push(error),
+            result => events.extend(result?),
+        }
+    }
+    events.sort_by_key(|event| event.start);
+    Ok((events, errors))
+}
+
+pub fn all_day_events(text: &str) -> Result<(Vec<AllDayEvent>, Vec<CalendarError>), CalendarError> {
+    let vevents = vevents(&Component::parse(text));
+    let mut overrides = vec![];
+    for properties in &vevents {
+        if let Some((_parameters, value)) = &properties.recurrence_id {
+            if value.len() == DATE_LENGTH {
+                overrides.push((properties.uid.clone(), date(value)?));
+            }
+        }
+    }
+
+    let mut events = vec![];
+    let mut errors = vec![];
+    for properties in vevents {
+        let overridden = overrides
+            .iter()
+            .filter(|(uid, _day)| properties.recurrence_id.is_none() && *uid == properties.uid)
+            .map(|(_uid, day)| *day)
+            .collect::<Vec<_>>();
+        match properties.to_all_day_event(&overridden) {
+            Err(error @ CalendarError::UnsupportedRecurrence(..)) => errors.push(error),
+            result => events.extend(result?),
+        }
+    }
+    Ok((events, errors))
+}
+
+impl AllDayEvent {
+    pub fn on(date: NaiveDate, summary: &str) -> Self {
+        Self {
+            start: date,
+            days: 1,
+            summary: summary.to_string(),
+            recurrence: None,
+            exceptions: vec![],
+        }
+    }
+
+    pub fn summary(&self) -> &str {
+        &self.summary
+    }
+
+    pub fn covers(&self, date: NaiveDate) -> bool {
+        let start = match &self.recurrence {
+            Some(recurrence) => {
+                let until = match recurrence.until() {
+                    Some(until) => until
+                        .get(..DATE_LENGTH)
+                        .and_then(|until| self::date(until).ok()),
+                    None => None,
+                };
+                recurrence
+                    .latest(
+                        self.start.and_hms(0, 0, 0),
+                        until.map_or(date, |until| until.min(date)),
+                    )
+                    .map(|start| start.date())
+            }
+            None => Some(self.start),
+        };
+        start.is_some_and(|start| {
+            start <= date
+                && date < start + Duration::days(self.days)
+                && !self.exceptions.contains(&start)
+        })
+    }
+}
+
+fn vevents(calendar: &Component) -> Vec<Properties> {
+    calendar
+        .all("VEVENT")
+        .into_iter()
+        .map(|vevent| {
+            let timed = |name| {
+                vevent
+                    .property(name)
+                    .map(|property| (property.parameters.clone(), property.value.clone()))
+            };
+            let value = |name| {
+                vevent
+                    .property(name)
+                    .map(|property| property.value.as_str())
+            };
+            Properties {
+                uid: value("UID").unwrap_or_default().to_string(),
+                start: timed("DTSTART"),
+                end: timed("DTEND"),
+                duration: value("DURATION").map(String::from),
+                summary: value("SUMMARY").map(unescape).unwrap_or_default(),
+                cancelled: value("STATUS") == Some("CANCELLED"),
+                recurrence: value("RRULE").map(String::from),
+                exceptions: vevent
+                    .properties_named("EXDATE")
+                    .flat_map(|property| {
+                        property
+                            .value
+                            .split(',')
+                            .map(move |value| (property.parameters.clone(), value.to_string()))
+                    })
+                    .collect(),
+                recurrence_id: timed("RECURRENCE-ID"),
+            }
+        })
+        .collect()
+}
+
+impl Properties {
+    fn to_all_day_event(
+        &self,
+        overridden: &[NaiveDate],
+    ) -> Result<Option<AllDayEvent>, CalendarError> {
+        let start = match &self.start {
+            Some((_parameters, start)) if !self.cancelled && start.len() == DATE_LENGTH => {
+                date(start)?
+            }
+            _ => return Ok(None),
+        };
+        let days = match (&self.end, &self.duration) {
+            (Some((_parameters, end)), _) => (date(end)? - start).num_days(),
+            (None, Some(duration)) => self::duration(duration)?.num_days(),
+            (None, None) => 1,
+        };
+        let recurrence = match (&self.recurrence, &self.recurrence_id) {
+            (Some(rule), None) => Some(Recurrence::parse(rule).ok_or_else(|| {
+                CalendarError::UnsupportedRecurrence(self.summary.clone(), rule.clone())
+            })?),
+            _ => None,
+        };
+        let mut exceptions = overridden.to_vec();
+        for (_parameters, value) in &self.exceptions {
+            exceptions.push(date(value.get(..DATE_LENGTH).unwrap_or(value))?);
+        }
+        Ok(Some(AllDayEvent {
+            start,
+            days: days.max(1),
+            summary: self.summary.clone(),
+            recurrence,
+            exceptions,
+        }))
+    }
+
+    fn to_events(
+        &self,
+        zones: &TimeZones,
+        to: NaiveDate,
+        overridden: &[DateTime<Local>],
+    ) -> Result<Vec<Event>, CalendarError> {
+        let (start_parameters, start) = match &self.start {
+            Some(start) if !self.cancelled => start,
+            _ => return Ok(vec![]),
+        };
+        let value_type = start_parameters.to_uppercase();
+        if value_type.contains("VALUE=DATE") && !value_type.contains("VALUE=DATE-TIME") {
+            return Ok(vec![]);
+        }
+        let first = time(zones, start_parameters, start)?;
+        let length = match (&self.end, &self.duration) {
+            (Some((parameters, end)), _) => time(zones, parameters, end)? - first,
+            (None, Some(duration)) => self::duration(duration)?,
+            (None, None) => return Ok(vec![]),
+        };
+        let starts = match (&self.recurrence, &self.recurrence_id) {
+            (Some(rule), None) => self.occurrences(zones, rule, start_parameters, start, to)?,
+            _ => vec![first],
+        };
+        let mut exceptions = vec![];
+        for (parameters, value) in &self.exceptions {
+            exceptions.push(match value.len() {
+                DATE_LENGTH => Err(date(value)?),
+                _ => Ok(time(zones, parameters, value)?),
+            });
+        }
+
+        Ok(starts
+            .into_iter()
+            .filter(|start| {
+                !overridden.contains(start)
+                    && !exceptions.contains(&Ok(*start))
+                    && !exceptions.contains(&Err(start.date().naive_local()))
+            })
+            .map(|start| Event {
+                start,
+                end: start + length,
+                summary: self.summary.clone(),
+            })
+            .collect())
+    }
+
+    fn occurrences(
+        &self,
+        zones: &TimeZones,
+        rule: &str,
+        parameters: &str,
+        start: &str,
+        to: NaiveDate,
+    ) -> Result<Vec<DateTime<Local>>, CalendarError> {
+        let recurrence = Recurrence::parse(rule).ok_or_else(|| {
+            CalendarError::UnsupportedRecurrence(self.summary.clone(), rule.to_string())
+        })?;
+        let utc = start.ends_with('Z');
+        let mut starts = vec![];
+        for occurrence in recurrence
+            .occurrences(naive_time(start)?)
+            .take_while(|occurrence| occurrence.date() <= to)
+        {
+            let start = localize(zones, parameters, utc, occurrence)?;
+            let after_until = match recurrence.until() {
+                Some(until) if until.len() == DATE_LENGTH => occurrence.date() > date(until)?,
+                Some(until) => start > time(zones, parameters, until)?,
+                None => false,
+            };
+            if after_until {
+                break;
+            }
+            starts.push(start);
+        }
+        Ok(starts)
+    }
+}
+
+fn time(
+    zones: &TimeZones,
+    parameters: &str,
+    value: &str,
+) -> Result<DateTime<Local>, CalendarError> {
+    localize(zones, parameters, value.ends_with('Z'), naive_time(value)?)
+}
+
+fn naive_time(value: &str) -> Result<NaiveDateTime, CalendarError> {
+    NaiveDateTime::parse_from_str(value.trim_end_matches('Z'), DATE_TIME_FORMAT)
+        .map_err(|_| CalendarError::InvalidTime(value.to_string()))
+}
+
+fn localize(
+    zones: &TimeZones,
+    parameters: &str,
+    utc: bool,
+    time: NaiveDateTime,
+) -> Result<DateTime<Local>, CalendarError> {
+    let invalid = || CalendarError::InvalidTime(time.format(DATE_TIME_FORMAT).to_string());
+    if utc {
+        return Ok(Utc.from_utc_datetime(&time).with_timezone(&Local));
+    }
+    match zone(parameters) {
+        Some(zone) => zones
+            .offset(zone, time)
+            .ok_or_else(|| CalendarError::UnknownTimeZone(zone.to_string()))?
+            .from_local_datetime(&time)
+            .single()
+            .map(|time| time.with_timezone(&Local))
+            .ok_or_else(invalid),
+        None => Local
+            .from_local_datetime(&time)
+            .earliest()
+            .ok_or_else(invalid),
+    }
+}
+
+fn date(value: &str) -> Result<NaiveDate, CalendarError> {
+    NaiveDate::parse_from_str(value, DATE_FORMAT)
+        .map_err(|_| CalendarError::InvalidTime(value.to_string()))
+}
+
+fn zone(parameters: &str) -> Option<&str> {
+    parameters.split(';').find_map(|parameter| {
+        let (name, value) = parameter.split_once('=')?;
+        match name.eq_ignore_ascii_case("TZID") {
+            true => Some(value.trim_matches('"')),
+            false => None,
+        }
+    })
+}
+
+fn duration(value: &str) -> Result<Duration, CalendarError> {
+    let invalid = || CalendarError::InvalidDuration(value.to_string());
+    let (sign, unsigned) = match value.strip_prefix('-') {
+        Some(unsigned) => (-1, unsigned),
+        None => (1, value.strip_prefix('+').unwrap_or(value)),
+    };
+    let designators = unsigned.strip_prefix('P').ok_or_else(invalid)?;
+    let mut duration = Duration::zero();
+    let mut number = String::new();
+    let mut in_time = false;
+    let mut units = 0;
+    for character in designators.chars() {
+        if character.is_ascii_digit() {
+            number.push(character);
+            continue;
+        }
+        if character == 'T' && number.is_empty() && !in_time {
+            in_time = true;
+            continue;
+        }
+        let amount = number.parse::<i64>().map_err(|_| invalid())?;
+        duration = duration
+            + match (in_time, character) {
+                (false, 'W') => Duration::weeks(amount),
+                (false, 'D') => Duration::days(amount),
+                (true, 'H') => Duration::hours(amount),
+                (true, 'M') => Duration::minutes(amount),
+                (true, 'S') => Duration::seconds(amount),
+                _ => return Err(invalid()),
+            };
+        number.clear();
+        units += 1;
+    }
+    if !number.is_empty() || units == 0 {
+        return Err(invalid());
+    }
+    Ok(duration * sign)
+}
+
+fn unescape(text: &str) -> String {
+    let mut unescaped = String::new();
+    let mut characters = text.chars();
+    while let Some(character) = characters.next() {
+        match character {
+            '\\' => match characters.next() {
+                Some('n') | Some('N') => unescaped.push(' '),
+                Some(escaped) => unescaped.push(escaped),
+                None => {}
+            },
+            _ => unescaped.push(character),
+        }
+    }
+    unescaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_reads_timed_events_in_order() {
+        let text = "BEGIN:VCALENDAR\r
+BEGIN:VEVENT\r
+DTSTART;TZID=UTC+0100:20200112T100000\r
+DURATION:PT1H30M\r
+SUMMARY:Sprint review\\, with\r
+  customer\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+DTSTART:20200112T080000Z\r
+DTEND:20200112T081500Z\r
+SUMMARY:Daily standup\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+DTSTART;VALUE=DATE:20200112\r
+SUMMARY:Holiday\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+DTSTART:20200112T120000Z\r
+DTEND:20200112T130000Z\r
+STATUS:CANCELLED\r
+SUMMARY:Lunch talk\r
+END:VEVENT\r
+END:VCALENDAR\r
+";
+
+        let (events, _errors) = events(text, NaiveDate::from_ymd(2020, 12, 31)).unwrap();
+
+        assert_eq!(
+            events,
+            vec![
+                Event {
+                    start: Utc.ymd(2020, 1, 12).and_hms(8, 0, 0).with_timezone(&Local),
+                    end: Utc.ymd(2020, 1, 12).and_hms(8, 15, 0).with_timezone(&Local),
+                    summary: "Daily standup".to_string(),
+                },
+                Event {
+                    start: Utc.ymd(2020, 1, 12).and_hms(9, 0, 0).with_timezone(&Local),
+                    end: Utc
+                        .ymd(2020, 1, 12)
+                        .and_hms(10, 30, 0)
+                        .with_timezone(&Local),
+                    summary: "Sprint review, with customer".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn it_ignores_properties_of_alarms() {
+        let text = "BEGIN:VEVENT\r
+DTSTART:20200112T080000Z\r
+DURATION:PT15M\r
+SUMMARY:Daily standup\r
+BEGIN:VALARM\r
+ACTION:DISPLAY\r
+DURATION:PT5M\r
+SUMMARY:Reminder\r
+END:VALARM\r
+END:VEVENT\r
+";
+
+        let (events, _errors) = events(text, NaiveDate::from_ymd(2020, 12, 31)).unwrap();
+
+        assert_eq!(
+            events,
+            vec![Event {
+                start: Utc.ymd(2020, 1, 12).and_hms(8, 0, 0).with_timezone(&Local),
+                end: Utc.ymd(2020, 1, 12).and_hms(8, 15, 0).with_timezone(&Local),
+                summary: "Daily standup".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn it_reads_durations_with_weeks_days_and_times() {
+        assert_eq!(duration("PT1H30M"), Ok(Duration::minutes(90)));
+        assert_eq!(duration("P1D"), Ok(Duration::days(1)));
+        assert_eq!(
+            duration("P1DT2H"),
+            Ok(Duration::days(1) + Duration::hours(2))
+        );
+        assert_eq!(duration("P1W"), Ok(Duration::weeks(1)));
+        assert_eq!(duration("-PT15M"), Ok(Duration::minutes(-15)));
+        assert_eq!(
+            duration("P1H"),
+            Err(CalendarError::InvalidDuration("P1H".to_string()))
+        );
+        assert_eq!(
+            duration("PT"),
+            Err(CalendarError::InvalidDuration("PT".to_string()))
+        );
+    }
+
+    #[test]
+    fn it_expands_recurring_events_up_to_the_given_day() {
+        let text = "BEGIN:VCALENDAR\r
+BEGIN:VEVENT\r
+UID:standup\r
+DTSTART:20200106T083000Z\r
+DTEND:20200106T084500Z\r
+RRULE:FREQ=WEEKLY;BYDAY=MO\r
+EXDATE:20200120T083000Z\r
+SUMMARY:Standup\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+UID:standup\r
+RECURRENCE-ID:20200127T083000Z\r
+DTSTART:20200128T090000Z\r
+DTEND:20200128T091500Z\r
+SUMMARY:Standup\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+UID:review\r
+DTSTART:20200106T120000Z\r
+DURATION:PT1H\r
+RRULE:FREQ=MONTHLY;BYSETPOS=-1;BYDAY=FR\r
+SUMMARY:Review\r
+END:VEVENT\r
+END:VCALENDAR\r
+";
+        let standup = |day, hour, minute| Event {
+            start: Utc
+                .ymd(2020, 1, day)
+                .and_hms(hour, minute, 0)
+                .with_timezone(&Local),
+            end: Utc
+                .ymd(2020, 1, day)
+                .and_hms(hour, minute + 15, 0)
+                .with_timezone(&Local),
+            summary: "Standup".to_string(),
+        };
+
+        let (events, errors) = events(text, NaiveDate::from_ymd(2020, 1, 31)).unwrap();
+
+        assert_eq!(
+            events,
+            vec![standup(6, 8, 30), standup(13, 8, 30), standup(28, 9, 0)]
+        );
+        assert_eq!(
+            errors,
+            vec![CalendarError::UnsupportedRecurrence(
+                "Review".to_string(),
+                "FREQ=MONTHLY;BYSETPOS=-1;BYDAY=FR".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn it_reads_times_in_time_zones_of_the_calendar() {
+        let text = "BEGIN:VCALENDAR\r
+BEGIN:VTIMEZONE\r
+TZID:W. Europe Standard Time\r
+BEGIN:STANDARD\r
+DTSTART:16010101T030000\r
+TZOFFSETFROM:+0200\r
+TZOFFSETTO:+0100\r
+RRULE:FREQ=YEARLY;BYDAY=-1SU;BYMONTH=10\r
+END:STANDARD\r
+BEGIN:DAYLIGHT\r
+DTSTART:16010101T020000\r
+TZOFFSETFROM:+0100\r
+TZOFFSETTO:+0200\r
+RRULE:FREQ=YEARLY;BYDAY=-1SU;BYMONTH=3\r
+END:DAYLIGHT\r
+END:VTIMEZONE\r
+BEGIN:VEVENT\r
+DTSTART;TZID=W. Europe Standard Time:20200713T093000\r
+DTEND;TZID=W. Europe Standard Time:20200713T100000\r
+SUMMARY:Planning\r
+END:VEVENT\r
+END:VCALENDAR\r
+";
+
+        let (events, _errors) = events(text, NaiveDate::from_ymd(2020, 12, 31)).unwrap();
+
+        assert_eq!(
+            events,
+            vec![Event {
+                start: Utc.ymd(2020, 7, 13).and_hms(7, 30, 0).with_timezone(&Local),
+                end: Utc.ymd(2020, 7, 13).and_hms(8, 0, 0).with_timezone(&Local),
+                summary: "Planning".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn it_reports_unknown_time_zones() {
+        let text =
+            "BEGIN:VEVENT\nDTSTART;TZID=Europe/Berlin:20200713T093000\nDURATION:PT1H\nEND:VEVENT\n";
+
+        assert_eq!(
+            events(text, NaiveDate::from_ymd(2020, 12, 31)),
+            Err(CalendarError::UnknownTimeZone("Europe/Berlin".to_string()))
+        );
+    }
+
+    #[test]
+    fn it_reports_invalid_times() {
+        let text = "BEGIN:VEVENT\nDTSTART:tomorrow\nDURATION:PT1H\nEND:VEVENT\n";
+
+        assert_eq!(
+            events(text, NaiveDate::from_ymd(2020, 12, 31)),
+            Err(CalendarError::InvalidTime("tomorrow".to_string()))
+        );
+    }
+
+    #[test]
+    fn it_reads_days_of_all_day_events() {
+        let text = "BEGIN:VCALENDAR\r
+BEGIN:VEVENT\r
+DTSTART;VALUE=DATE:20201224\r
+DTEND;VALUE=DATE:20201227\r
+SUMMARY:Christmas\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+DTSTART;VALUE=DATE:20200101\r
+SUMMARY:New Year\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+DTSTART:20200112T080000Z\r
+SUMMARY:Meeting\r
+END:VEVENT\r
+END:VCALENDAR\r
+";
+
+        let (events, _errors) = all_day_events(text).unwrap();
+        let days = NaiveDate::from_ymd(2020, 1, 1)
+            .iter_days()
+            .take(366)
+            .flat_map(|day| {
+                events
+                    .iter()
+                    .filter(move |event| event.covers(day))
+                    .map(move |event| (day, event.summary().to_string()))
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            days,
+            vec![
+                (NaiveDate::from_ymd(2020, 1, 1), "New Year".to_string()),
+                (NaiveDate::from_ymd(2020, 12, 24), "Christmas".to_string()),
+                (NaiveDate::from_ymd(2020, 12, 25), "Christmas".to_string()),
+                (NaiveDate::from_ymd(2020, 12, 26), "Christmas".to_string()),
+            ]
+        );
+    }
+}
@@ -0,0 +1,6 @@
+pub mod calendar_error;
+pub mod component;
+pub mod drafts;
+pub mod event;
+pub mod recurrence;
+pub mod time_zone;
@@ -0,0 +1,332 @@
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime, Weekday};
+
+const MAX_YEARS_WITHOUT_OCCURRENCE: i64 = 8;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum Frequency {
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Recurrence {
+    frequency: Frequency,
+    interval: i64,
+    count: Option<usize>,
+    until: Option<String>,
+    by_day: Vec<(Option<i64>, Weekday)>,
+    by_month: Vec<u32>,
+    by_month_day: Vec<i64>,
+    week_start: Weekday,
+}
+
+impl Recurrence {
+    pub fn parse(rule: &str) -> Option<Self> {
+        let mut frequency = None;
+        let mut recurrence = Recurrence {
+            frequency: Frequency::Daily,
+            interval: 1,
+            count: None,
+            until: None,
+            by_day: vec![],
+            by_month: vec![],
+            by_month_day: vec![],
+            week_start: Weekday::Mon,
+        };
+        for part in rule.split(';').filter(|part| !part.is_empty()) {
+            let (name, value) = part.split_once('=')?;
+            let list = value.split(',');
+            match name.to_uppercase().as_str() {
+                "FREQ" => {
+                    frequency = Some(match value.to_uppercase().as_str() {
+                        "DAILY" => Frequency::Daily,
+                        "WEEKLY" => Frequency::Weekly,
+                        "MONTHLY" => Frequency::Monthly,
+                        "YEARLY" => Frequency::Yearly,
+                        _ => return None,
+                    })
+                }
+                "INTERVAL" => recurrence.interval = value.parse().ok().filter(|n| *n > 0)?,
+                "COUNT" => recurrence.count = Some(value.parse().ok()?),
+                "UNTIL" => recurrence.until = Some(value.to_string()),
+                "BYDAY" => recurrence.by_day = list.map(week_day).collect::<Option<_>>()?,
+                "BYMONTH" => {
+                    recurrence.by_month = list
+                        .map(|month| month.parse().ok().filter(|month| (1..=12).contains(month)))
+                        .collect::<Option<_>>()?
+                }
+                "BYMONTHDAY" => {
+                    recurrence.by_month_day = list
+                        .map(|day| {
+                            day.parse()
+                                .ok()
+                                .filter(|day: &i64| (1..=31).contains(&day.abs()))
+                        })
+                        .collect::<Option<_>>()?
+                }
+                "WKST" => recurrence.week_start = weekday(value)?,
+                _ => return None,
+            }
+        }
+        recurrence.frequency = frequency?;
+        Some(recurrence)
+    }
+
+    pub fn until(&self) -> Option<&str> {
+        self.until.as_deref()
+    }
+
+    pub fn occurrences(&self, start: NaiveDateTime) -> impl Iterator<Item = NaiveDateTime> + '_ {
+        let mut day = start.date();
+        let mut found = 0;
+        std::iter::from_fn(move || {
+            if self.count.is_some_and(|count| found >= count) {
+                return None;
+            }
+            let last = day + Duration::days(366 * MAX_YEARS_WITHOUT_OCCURRENCE * self.interval);
+            while day <= last {
+                let candidate = day;
+                day = day.succ();
+                if self.matches(start.date(), candidate) {
+                    found += 1;
+                    return Some(candidate.and_time(start.time()));
+                }
+            }
+            None
+        })
+    }
+
+    pub fn latest(&self, start: NaiveDateTime, date: NaiveDate) -> Option<NaiveDateTime> {
+        if self.count.is_some() {
+            return self
+                .occurrences(start)
+                .take_while(|occurrence| occurrence.date() <= date)
+                .last();
+        }
+        let first = (date - Duration::days(366 * MAX_YEARS_WITHOUT_OCCURRENCE * self.interval))
+            .max(start.date());
+        let mut day = date;
+        while day >= first {
+            if self.matches(start.date(), day) {
+                return Some(day.and_time(start.time()));
+            }
+            day = day.pred();
+        }
+        None
+    }
+
+    fn matches(&self, start: NaiveDate, day: NaiveDate) -> bool {
+        if day <= start {
+            return day == start;
+        }
+        let period = match self.frequency {
+            Frequency::Daily => (day - start).num_days(),
+            Frequency::Weekly => (self.week_of(day) - self.week_of(start)).num_days() / 7,
+            Frequency::Monthly => months(day) - months(start),
+            Frequency::Yearly => i64::from(day.year() - start.year()),
+        };
+        if period % self.interval != 0 {
+            return false;
+        }
+
+        let by_month = match (self.frequency, self.by_month.is_empty()) {
+            (Frequency::Yearly, true) if self.by_day.is_empty() => vec![start.month()],
+            _ => self.by_month.clone(),
+        };
+        let by_month_day = match self.frequency {
+            Frequency::Monthly | Frequency::Yearly
+                if self.by_month_day.is_empty() && self.by_day.is_empty() =>
+            {
+                vec![i64::from(start.day())]
+            }
+            _ => self.by_month_day.clone(),
+        };
+        let by_day = match self.frequency {
+            Frequency::Weekly if self.by_day.is_empty() => vec![(None, start.weekday())],
+            _ => self.by_day.clone(),
+        };
+        let ordinal_in_month = self.frequency == Frequency::Monthly
+            || (self.frequency == Frequency::Yearly && !by_month.is_empty());
+
+        (by_month.is_empty() || by_month.contains(&day.month()))
+            && (by_month_day.is_empty()
+                || by_month_day
+                    .iter()
+                    .any(|month_day| is_nth(*month_day, day.day(), days_in_month(day))))
+            && (by_day.is_empty()
+                || by_day.iter().any(|(nth, weekday)| {
+                    *weekday == day.weekday()
+                        && nth.is_none_or(|nth| match self.frequency {
+                            Frequency::Monthly | Frequency::Yearly if ordinal_in_month => {
+                                is_nth_week(nth, day.day(), days_in_month(day))
+                            }
+                            Frequency::Yearly => {
+                                is_nth_week(nth, day.ordinal(), days_in_year(day.year()))
+                            }
+                            _ => true,
+                        })
+                }))
+    }
+
+    fn week_of(&self, day: NaiveDate) -> NaiveDate {
+        let offset =
+            (7 + day.weekday().num_days_from_monday() - self.week_start.num_days_from_monday()) % 7;
+        day - Duration::days(i64::from(offset))
+    }
+}
+
+fn week_day(value: &str) -> Option<(Option<i64>, Weekday)> {
+    let split = value.len().checked_sub(2)?;
+    let (nth, day) = value.split_at(split);
+    let nth = match nth.trim_start_matches('+') {
+        "" => None,
+        nth => Some(
+            nth.parse()
+                .ok()
+                .filter(|nth: &i64| (1..=53).contains(&nth.abs()))?,
+        ),
+    };
+    Some((nth, weekday(day)?))
+}
+
+fn weekday(value: &str) -> Option<Weekday> {
+    match value.to_uppercase().as_str() {
+        "MO" => Some(Weekday::Mon),
+        "TU" => Some(Weekday::Tue),
+        "WE" => Some(Weekday::Wed),
+        "TH" => Some(Weekday::Thu),
+        "FR" => Some(Weekday::Fri),
+        "SA" => Some(Weekday::Sat),
+        "SU" => Some(Weekday::Sun),
+        _ => None,
+    }
+}
+
+fn is_nth(nth: i64, index: u32, length: u32) -> bool {
+    match nth > 0 {
+        true => i64::from(index) == nth,
+        false => i64::from(length) + 1 + nth == i64::from(index),
+    }
+}
+
+fn is_nth_week(nth: i64, index: u32, length: u32) -> bool {
+    match nth > 0 {
+        true => i64::from((index - 1) / 7 + 1) == nth,
+        false => -i64::from((length - index) / 7 + 1) == nth,
+    }
+}
+
+fn months(day: NaiveDate) -> i64 {
+    i64::from(day.year()) * 12 + i64::from(day.month0())
+}
+
+fn days_in_month(day: NaiveDate) -> u32 {
+    let (year, month) = match day.month() {
+        12 => (day.year() + 1, 1),
+        month => (day.year(), month + 1),
+    };
+    NaiveDate::from_ymd(year, month, 1).pred().day()
+}
+
+fn days_in_year(year: i32) -> u32 {
+    NaiveDate::from_ymd(year, 12, 31).ordinal()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dates(rule: &str, start: NaiveDate, to: NaiveDate) -> Vec<NaiveDate> {
+        Recurrence::parse(rule)
+            .unwrap()
+            .occurrences(start.and_hms(9, 30, 0))
+            .map(|occurrence| occurrence.date())
+            .take_while(|date| *date <= to)
+            .collect()
+    }
+
+    #[test]
+    fn it_repeats_on_week_days() {
+        assert_eq!(
+            dates(
+                "FREQ=WEEKLY;INTERVAL=2;BYDAY=MO,TH",
+                NaiveDate::from_ymd(2020, 1, 6),
+                NaiveDate::from_ymd(2020, 1, 31)
+            ),
+            vec![
+                NaiveDate::from_ymd(2020, 1, 6),
+                NaiveDate::from_ymd(2020, 1, 9),
+                NaiveDate::from_ymd(2020, 1, 20),
+                NaiveDate::from_ymd(2020, 1, 23),
+            ]
+        );
+    }
+
+    #[test]
+    fn it_repeats_on_the_nth_week_day_and_stops_after_count() {
+        assert_eq!(
+            dates(
+                "FREQ=YEARLY;BYMONTH=3;BYDAY=-1SU;COUNT=3",
+                NaiveDate::from_ymd(2020, 3, 29),
+                NaiveDate::from_ymd(2030, 1, 1)
+            ),
+            vec![
+                NaiveDate::from_ymd(2020, 3, 29),
+                NaiveDate::from_ymd(2021, 3, 28),
+                NaiveDate::from_ymd(2022, 3, 27),
+            ]
+        );
+    }
+
+    #[test]
+    fn it_repeats_yearly_and_monthly_on_the_day_of_the_start() {
+        assert_eq!(
+            dates(
+                "FREQ=YEARLY",
+                NaiveDate::from_ymd(2020, 12, 24),
+                NaiveDate::from_ymd(2022, 12, 31)
+            ),
+            vec![
+                NaiveDate::from_ymd(2020, 12, 24),
+                NaiveDate::from_ymd(2021, 12, 24),
+                NaiveDate::from_ymd(2022, 12, 24),
+            ]
+        );
+        assert_eq!(
+            dates(
+                "FREQ=MONTHLY",
+                NaiveDate::from_ymd(2020, 1, 31),
+                NaiveDate::from_ymd(2020, 5, 31)
+            ),
+            vec![
+                NaiveDate::from_ymd(2020, 1, 31),
+                NaiveDate::from_ymd(2020, 3, 31),
+                NaiveDate::from_ymd(2020, 5, 31),
+            ]
+        );
+    }
+
+    #[test]
+    fn it_finds_the_latest_occurrence() {
+        let recurrence = Recurrence::parse("FREQ=YEARLY;BYMONTH=10;BYDAY=-1SU").unwrap();
+        let start = NaiveDate::from_ymd(1996, 10, 27).and_hms(3, 0, 0);
+
+        assert_eq!(
+            recurrence.latest(start, NaiveDate::from_ymd(2020, 7, 1)),
+            Some(NaiveDate::from_ymd(2019, 10, 27).and_hms(3, 0, 0))
+        );
+        assert_eq!(
+            recurrence.latest(start, NaiveDate::from_ymd(1996, 7, 1)),
+            None
+        );
+    }
+
+    #[test]
+    fn it_rejects_rules_it_cannot_expand() {
+        assert_eq!(Recurrence::parse("FREQ=HOURLY"), None);
+        assert_eq!(Recurrence::parse("FREQ=MONTHLY;BYSETPOS=-1;BYDAY=MO"), None);
+        assert_eq!(Recurrence::parse("BYDAY=MO"), None);
+    }
+}
@@ -0,0 +1,174 @@
+use crate::calendar::{component::Component, recurrence::Recurrence};
+use chrono::{FixedOffset, NaiveDateTime};
+use std::collections::HashMap;
+
+const DATE_TIME_FORMAT: &str = "%Y%m%dT%H%M%S";
+
+#[derive(Debug, PartialEq)]
+struct Observance {
+    start: NaiveDateTime,
+    offset_from: FixedOffset,
+    offset_to: FixedOffset,
+    recurrence: Option<Recurrence>,
+    dates: Vec<NaiveDateTime>,
+}
+
+#[derive(Debug, PartialEq, Default)]
+pub struct TimeZones {
+    zones: HashMap<String, Vec<Observance>>,
+}
+
+impl TimeZones {
+    pub fn parse(calendar: &Component) -> Self {
+        let zones = calendar
+            .all("VTIMEZONE")
+            .into_iter()
+            .filter_map(|zone| {
+                let id = zone.property("TZID")?.value.clone();
+                let observances = zone
+                    .components
+                    .iter()
+                    .filter(|observance| {
+                        observance.name == "STANDARD" || observance.name == "DAYLIGHT"
+                    })
+                    .filter_map(observance)
+                    .collect::<Vec<_>>();
+                Some((id, observances))
+            })
+            .collect();
+        Self { zones }
+    }
+
+    pub fn offset(&self, zone: &str, time: NaiveDateTime) -> Option<FixedOffset> {
+        let observances = match self.zones.get(zone) {
+            Some(observances) if !observances.is_empty() => observances,
+            _ => return utc_offset(zone),
+        };
+        let latest = observances
+            .iter()
+            .filter_map(|observance| Some((observance.onset(time)?, observance)))
+            .max_by_key(|(onset, _observance)| *onset);
+        match latest {
+            Some((_onset, observance)) => Some(observance.offset_to),
+            None => observances
+                .iter()
+                .min_by_key(|observance| observance.start)
+                .map(|observance| observance.offset_from),
+        }
+    }
+}
+
+impl Observance {
+    fn onset(&self, time: NaiveDateTime) -> Option<NaiveDateTime> {
+        let recurring = self.recurrence.as_ref().and_then(|recurrence| {
+            let limit = match recurrence.until().map(naive_time) {
+                Some(Some(until)) => until.min(time),
+                _ => time,
+            };
+            let latest = recurrence.latest(self.start, limit.date())?;
+            match latest <= limit {
+                true => Some(latest),
+                false => recurrence.latest(self.start, limit.date().pred()),
+            }
+        });
+        self.dates
+            .iter()
+            .chain(std::iter::once(&self.start))
+            .copied()
+            .chain(recurring)
+            .filter(|onset| *onset <= time)
+            .max()
+    }
+}
+
+fn observance(component: &Component) -> Option<Observance> {
+    let value = |name| {
+        component
+            .property(name)
+            .map(|property| property.value.as_str())
+    };
+    Some(Observance {
+        start: naive_time(value("DTSTART")?)?,
+        offset_from: fixed_offset(value("TZOFFSETFROM")?)?,
+        offset_to: fixed_offset(value("TZOFFSETTO")?)?,
+        recurrence: value("RRULE").and_then(Recurrence::parse),
+        dates: component
+            .properties_named("RDATE")
+            .flat_map(|property| property.value.split(','))
+            .filter_map(naive_time)
+            .collect(),
+    })
+}
+
+fn naive_time(value: &str) -> Option<NaiveDateTime> {
+    NaiveDateTime::parse_from_str(value.trim_end_matches('Z'), DATE_TIME_FORMAT).ok()
+}
+
+fn utc_offset(zone: &str) -> Option<FixedOffset> {
+    fixed_offset(zone.strip_prefix("UTC")?)
+}
+
+fn fixed_offset(value: &str) -> Option<FixedOffset> {
+    let sign = match value.chars().next()? {
+        '+' => 1,
+        '-' => -1,
+        _ => return None,
+    };
+    let digits = value[1..].replace(':', "");
+    if digits.len() != 4 && digits.len() != 6 {
+        return None;
+    }
+    let number = |range: std::ops::Range<usize>| digits.get(range)?.parse::<i32>().ok();
+    let seconds = number(0..2)? * 3600 + number(2..4)? * 60 + number(4..6).unwrap_or(0);
+    FixedOffset::east_opt(sign * seconds)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    const BERLIN: &str = "BEGIN:VCALENDAR\r
+BEGIN:VTIMEZONE\r
+TZID:Europe/Berlin\r
+BEGIN:DAYLIGHT\r
+TZOFFSETFROM:+0100\r
+TZOFFSETTO:+0200\r
+DTSTART:19810329T020000\r
+RRULE:FREQ=YEARLY;BYMONTH=3;BYDAY=-1SU\r
+END:DAYLIGHT\r
+BEGIN:STANDARD\r
+TZOFFSETFROM:+0200\r
+TZOFFSETTO:+0100\r
+DTSTART:19961027T030000\r
+RRULE:FREQ=YEARLY;BYMONTH=10;BYDAY=-1SU\r
+END:STANDARD\r
+END:VTIMEZONE\r
+END:VCALENDAR\r
+";
+
+    fn offset(zone: &str, month: u32, day: u32, hour: u32) -> Option<i32> {
+        TimeZones::parse(&Component::parse(BERLIN))
+            .offset(
+                zone,
+                NaiveDate::from_ymd(2020, month, day).and_hms(hour, 0, 0),
+            )
+            .map(|offset| offset.local_minus_utc() / 3600)
+    }
+
+    #[test]
+    fn it_reads_offsets_of_time_zones_with_daylight_saving_time() {
+        assert_eq!(offset("Europe/Berlin", 1, 13, 9), Some(1));
+        assert_eq!(offset("Europe/Berlin", 3, 29, 1), Some(1));
+        assert_eq!(offset("Europe/Berlin", 3, 29, 3), Some(2));
+        assert_eq!(offset("Europe/Berlin", 7, 1, 9), Some(2));
+        assert_eq!(offset("Europe/Berlin", 10, 25, 4), Some(1));
+    }
+
+    #[test]
+    fn it_reads_utc_offsets_and_rejects_unknown_zones() {
+        assert_eq!(offset("UTC+0100", 7, 1, 9), Some(1));
+        assert_eq!(offset("UTC-0300", 7, 1, 9), Some(-3));
+        assert_eq!(offset("W. Europe Standard Time", 7, 1, 9), None);
+    }
+}
@@ -0,0 +1,705 @@
+use chrono::{DateTime, Duration, Local, NaiveDate, NaiveTime, Timelike, Weekday};
+use serde::{de, Deserialize, Deserializer};
+use std::path::{Path, PathBuf};
+
+#[derive(Deserialize, Debug, PartialEq, Default)]
+pub struct Config {
+    #[serde(default)]
+    projects: Vec<PathBuf>,
+    #[serde(default)]
+    harvest: HarvestConfig,
+    #[serde(default)]
+    jira_issues: Option<PathBuf>,
+    #[serde(default)]
+    csv: CsvConfig,
+    #[serde(default)]
+    table: TableConfig,
+    #[serde(default)]
+    calendar: CalendarConfig,
+    #[serde(default)]
+    rules: RulesConfig,
+    #[serde(default)]
+    schedules: Vec<ScheduleConfig>,
+    #[serde(default)]
+    holidays: HolidaysConfig,
+}
+
+impl Config {
+    pub fn from(string: &str) -> Result<Config, toml::de::Error> {
+        toml::from_str(string)
+    }
+
+    pub fn relative_to(mut self, directory: &Path) -> Self {
+        self.projects = self
+            .projects
+            .into_iter()
+            .map(|file| directory.join(file))
+            .collect();
+        self.jira_issues = self.jira_issues.map(|file| directory.join(file));
+        self.holidays.files = self
+            .holidays
+            .files
+            .into_iter()
+            .map(|file| directory.join(file))
+            .collect();
+        self
+    }
+
+    pub fn projects(&self) -> &[PathBuf] {
+        &self.projects
+    }
+
+    pub fn harvest(&self) -> &HarvestConfig {
+        &self.harvest
+    }
+
+    pub fn jira_issues(&self) -> Option<&Path> {
+        self.jira_issues.as_deref()
+    }
+
+    pub fn csv(&self) -> &CsvConfig {
+        &self.csv
+    }
+
+    pub fn table(&self) -> &TableConfig {
+        &self.table
+    }
+
+    pub fn calendar(&self) -> &CalendarConfig {
+        &self.calendar
+    }
+
+    pub fn rules(&self) -> &RulesConfig {
+        &self.rules
+    }
+
+    pub fn schedules(&self) -> &[ScheduleConfig] {
+        &self.schedules
+    }
+
+    pub fn holidays(&self) -> &HolidaysConfig {
+        &self.holidays
+    }
+}
+
+#[derive(Deserialize, Debug, PartialEq, Default, Clone)]
+pub struct HarvestConfig {
+    #[serde(default)]
+    pub account_id: Option<String>,
+    #[serde(default)]
+    pub token: Option<String>,
+    #[serde(default)]
+    pub base_url: Option<String>,
+    #[serde(default)]
+    pub cache_ttl_hours: Option<i64>,
+}
+
+impl HarvestConfig {
+    pub fn with_environment<F>(mut self, variable: F) -> Self
+    where
+        F: Fn(&str) -> Option<String>,
+    {
+        self.account_id = variable("HARVEST_ACCOUNT_ID").or(self.account_id);
+        self.token = variable("HARVEST_ACCESS_TOKEN").or(self.token);
+        self.base_url = variable("HARVEST_BASE_URL").or(self.base_url);
+        self
+    }
+}
+
+#[derive(Deserialize, Debug, PartialEq, Default, Clone)]
+pub struct TableConfig {
+    #[serde(default)]
+    pub description_takes_rest: bool,
+}
+
+#[derive(Deserialize, Debug, PartialEq, Default, Clone)]
+pub struct RulesConfig {
+    #[serde(default)]
+    pub breaks: BreakConfig,
+    #[serde(default)]
+    pub rounding: Vec<RoundingConfig>,
+    #[serde(default)]
+    pub gaps: GapConfig,
+    #[serde(default)]
+    pub working_time: WorkingTimeConfig,
+    #[serde(default)]
+    pub rest: RestConfig,
+    #[serde(default)]
+    pub weekends: WeekendConfig,
+}
+
+#[derive(Deserialize, Debug, PartialEq, Clone)]
+#[serde(default)]
+pub struct BreakConfig {
+    pub enabled: bool,
+    pub minimum_minutes: i64,
+    pub max_continuous_hours: f64,
+    pub thresholds: Vec<BreakThreshold>,
+}
+
+#[derive(Deserialize, Debug, PartialEq, Clone)]
+pub struct BreakThreshold {
+    pub work_hours: f64,
+    pub break_minutes: i64,
+}
+
+impl Default for BreakConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            minimum_minutes: 15,
+            max_continuous_hours: 6.0,
+            thresholds: vec![
+                BreakThreshold {
+                    work_hours: 6.0,
+                    break_minutes: 30,
+                },
+                BreakThreshold {
+                    work_hours: 9.0,
+                    break_minutes: 45,
+                },
+            ],
+        }
+    }
+}
+
+impl BreakConfig {
+    pub fn minimum_break(&self) -> Duration {
+        Duration::minutes(self.minimum_minutes)
+    }
+
+    pub fn max_continuous_work(&self) -> Duration {
+        hours(self.max_continuous_hours)
+    }
+
+    pub fn required_break(&self, worked: Duration) -> Duration {
+        self.thresholds
+            .iter()
+            .filter(|threshold| worked > hours(threshold.work_hours))
+            .map(|threshold| Duration::minutes(threshold.break_minutes))
+            .max()
+            .unwrap_or_else(Duration::zero)
+    }
+}
+
+#[derive(Deserialize, Debug, PartialEq, Clone)]
+pub struct RoundingConfig {
+    pub project: Option<String>,
+    pub minutes: i64,
+    #[serde(default)]
+    pub direction: RoundingDirection,
+}
+
+#[derive(Deserialize, Debug, PartialEq, Clone, Copy, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum RoundingDirection {
+    #[default]
+    Nearest,
+    Up,
+    Down,
+}
+
+impl RoundingConfig {
+    pub fn is_on_grid(&self, time: &DateTime<Local>) -> bool {
+        self.floor(time) == *time
+    }
+
+    pub fn round(&self, time: &DateTime<Local>) -> DateTime<Local> {
+        match self.direction {
+            RoundingDirection::Up => self.ceil(time),
+            RoundingDirection::Down => self.floor(time),
+            RoundingDirection::Nearest => {
+                if *time - self.floor(time) < self.ceil(time) - *time {
+                    self.floor(time)
+                } else {
+                    self.ceil(time)
+                }
+            }
+        }
+    }
+
+    pub fn floor(&self, time: &DateTime<Local>) -> DateTime<Local> {
+        let time = time.with_nanosecond(0).unwrap();
+        let seconds = i64::from(time.num_seconds_from_midnight());
+        time - Duration::seconds(seconds % self.granularity())
+    }
+
+    pub fn ceil(&self, time: &DateTime<Local>) -> DateTime<Local> {
+        let floor = self.floor(time);
+        if floor == *time {
+            floor
+        } else {
+            floor + Duration::seconds(self.granularity())
+        }
+    }
+
+    pub fn granularity(&self) -> i64 {
+        self.minutes.max(1) * 60
+    }
+}
+
+#[derive(Deserialize, Debug, PartialEq, Clone)]
+#[serde(default)]
+pub struct GapConfig {
+    pub enabled: bool,
+    pub minimum_minutes: i64,
+    pub maximum_break_minutes: i64,
+    pub working_hours: WorkingHours,
+}
+
+#[derive(Deserialize, Debug, PartialEq, Clone)]
+pub struct WorkingHours {
+    #[serde(deserialize_with = "clock_time")]
+    pub start: NaiveTime,
+    #[serde(deserialize_with = "clock_time")]
+    pub end: NaiveTime,
+}
+
+impl Default for GapConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            minimum_minutes: 15,
+            maximum_break_minutes: 60,
+            working_hours: WorkingHours {
+                start: NaiveTime::from_hms(8, 0, 0),
+                end: NaiveTime::from_hms(18, 0, 0),
+            },
+        }
+    }
+}
+
+impl GapConfig {
+    pub fn minimum_gap(&self) -> Duration {
+        Duration::minutes(self.minimum_minutes)
+    }
+
+    pub fn maximum_break(&self) -> Duration {
+        Duration::minutes(self.maximum_break_minutes)
+    }
+}
+
+#[derive(Deserialize, Debug, PartialEq, Clone)]
+#[serde(default)]
+pub struct WorkingTimeConfig {
+    pub enabled: bool,
+    pub max_daily_hours: f64,
+    pub max_weekly_hours: Option<f64>,
+}
+
+impl Default for WorkingTimeConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_daily_hours: 10.0,
+            max_weekly_hours: None,
+        }
+    }
+}
+
+impl WorkingTimeConfig {
+    pub fn max_daily(&self) -> Duration {
+        hours(self.max_daily_hours)
+    }
+
+    pub fn max_weekly(&self) -> Option<Duration> {
+        self.max_weekly_hours.map(hours)
+    }
+}
+
+#[derive(Deserialize, Debug, PartialEq, Clone)]
+#[serde(default)]
+pub struct RestConfig {
+    pub enabled: bool,
+    pub minimum_hours: f64,
+    pub exempt_projects: Vec<String>,
+}
+
+impl Default for RestConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            minimum_hours: 11.0,
+            exempt_projects: vec![],
+        }
+    }
+}
+
+impl RestConfig {
+    pub fn minimum_rest(&self) -> Duration {
+        hours(self.minimum_hours)
+    }
+}
+
+#[derive(Deserialize, Debug, PartialEq, Clone, Default)]
+#[serde(default)]
+pub struct ScheduleConfig {
+    pub from: Option<NaiveDate>,
+    pub monday: f64,
+    pub tuesday: f64,
+    pub wednesday: f64,
+    pub thursday: f64,
+    pub friday: f64,
+    pub saturday: f64,
+    pub sunday: f64,
+}
+
+impl ScheduleConfig {
+    pub fn hours_on(&self, weekday: Weekday) -> Duration {
+        hours(match weekday {
+            Weekday::Mon => self.monday,
+            Weekday::Tue => self.tuesday,
+            Weekday::Wed => self.wednesday,
+            Weekday::Thu => self.thursday,
+            Weekday::Fri => self.friday,
+            Weekday::Sat => self.saturday,
+            Weekday::Sun => self.sunday,
+        })
+    }
+}
+
+#[derive(Deserialize, Debug, PartialEq, Clone, Default)]
+#[serde(default)]
+pub struct WeekendConfig {
+    pub enabled: bool,
+    pub allowed_projects: Vec<String>,
+}
+
+#[derive(Deserialize, Debug, PartialEq, Default)]
+pub struct HolidaysConfig {
+    pub state: Option<String>,
+    #[serde(default)]
+    pub files: Vec<PathBuf>,
+}
+
+pub fn hours(hours: f64) -> Duration {
+    Duration::minutes((hours * 60.0).round() as i64)
+}
+
+#[derive(Deserialize, Debug, PartialEq, Default, Clone)]
+pub struct CalendarConfig {
+    #[serde(default)]
+    pub rules: Vec<CalendarRule>,
+}
+
+#[derive(Deserialize, Debug, PartialEq, Clone)]
+pub struct CalendarRule {
+    pub title: String,
+    pub project: String,
+    pub task: String,
+    #[serde(default)]
+    pub description: Option<String>,
+}
+
+#[derive(Deserialize, Debug, PartialEq, Default, Clone)]
+pub struct CsvConfig {
+    #[serde(default, deserialize_with = "ascii_character")]
+    pub delimiter: Option<u8>,
+    #[serde(default)]
+    pub columns: CsvColumns,
+}
+
+#[derive(Deserialize, Debug, PartialEq, Clone)]
+#[serde(default)]
+pub struct CsvColumns {
+    pub start: String,
+    pub end: String,
+    pub project: String,
+    pub task: String,
+    pub description: String,
+}
+
+impl Default for CsvColumns {
+    fn default() -> Self {
+        Self {
+            start: "Start".to_string(),
+            end: "End".to_string(),
+            project: "Project".to_string(),
+            task: "Task".to_string(),
+            description: "Description".to_string(),
+        }
+    }
+}
+
+fn ascii_character<'de, D>(deserializer: D) -> Result<Option<u8>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let character = char::deserialize(deserializer)?;
+    if !character.is_ascii() {
+        return Err(de::Error::custom("delimiter has to be an ascii character"));
+    }
+    Ok(Some(character as u8))
+}
+
+fn clock_time<'de, D>(deserializer: D) -> Result<NaiveTime, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let time = String::deserialize(deserializer)?;
+    NaiveTime::parse_from_str(&time, "%H:%M")
+        .or_else(|_| NaiveTime::parse_from_str(&time, "%H:%M:%S"))
+        .map_err(|_| de::Error::custom(format!("'{}' is no time like 08:00", time)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn it_parses_projects_files() {
+        let config = Config::from(
+            r#"
+projects = ["harvest.json", "/home/me/internal.toml"]
+jira_issues = "issues.csv"
+
+[holidays]
+state = "BY"
+files = ["company.ics"]
+"#,
+        )
+        .unwrap()
+        .relative_to(Path::new("config"));
+
+        assert_eq!(
+            config.projects(),
+            &[
+                PathBuf::from("config/harvest.json"),
+                PathBuf::from("/home/me/internal.toml")
+            ]
+        );
+        assert_eq!(config.jira_issues(), Some(Path::new("config/issues.csv")));
+        assert_eq!(config.holidays().state.as_deref(), Some("BY"));
+        assert_eq!(
+            config.holidays().files,
+            vec![PathBuf::from("config/company.ics")]
+        );
+    }
+
+    #[test]
+    fn it_defaults_to_no_projects_files() {
+        let config = Config::from("").unwrap();
+
+        assert_eq!(config, Config::default());
+    }
+
+    #[test]
+    fn it_overrides_harvest_settings_with_environment() {
+        let config = Config::from(
+            r#"
+[harvest]
+account_id = "123"
+token = "from config"
+"#,
+        )
+        .unwrap();
+
+        let harvest = config
+            .harvest()
+            .clone()
+            .with_environment(|name| match name {
+                "HARVEST_ACCESS_TOKEN" => Some("from environment".to_string()),
+                _ => None,
+            });
+
+        assert_eq!(
+            harvest,
+            HarvestConfig {
+                account_id: Some("123".to_string()),
+                token: Some("from environment".to_string()),
+                base_url: None,
+                cache_ttl_hours: None,
+            }
+        );
+    }
+
+    #[test]
+    fn it_parses_csv_settings() {
+        let config = Config::from(
+            r#"
+[csv]
+delimiter = ";"
+
+[csv.columns]
+start = "Von"
+end = "Bis"
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            config.csv(),
+            &CsvConfig {
+                delimiter: Some(b';'),
+                columns: CsvColumns {
+                    start: "Von".to_string(),
+                    end: "Bis".to_string(),
+                    ..CsvColumns::default()
+                }
+            }
+        );
+    }
+
+    #[test]
+    fn it_rejects_non_ascii_csv_delimiters() {
+        let config = Config::from("[csv]\ndelimiter = \"§\"\n");
+
+        assert!(config.is_err());
+    }
+
+    #[test]
+    fn it_parses_calendar_rules() {
+        let config = Config::from(
+            r#"
+[[calendar.rules]]
+title = "(?i)standup"
+project = "Timeval"
+task = "Meeting"
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            config.calendar().rules,
+            vec![CalendarRule {
+                title: "(?i)standup".to_string(),
+                project: "Timeval".to_string(),
+                task: "Meeting".to_string(),
+                description: None,
+            }]
+        );
+    }
+
+    #[test]
+    fn it_requires_breaks_by_worked_time() {
+        let breaks = Config::from("").unwrap().rules().breaks.clone();
+
+        assert_eq!(breaks.required_break(hours(6.0)), Duration::zero());
+        assert_eq!(breaks.required_break(hours(6.5)), Duration::minutes(30));
+        assert_eq!(breaks.required_break(hours(9.25)), Duration::minutes(45));
+    }
+
+    #[test]
+    fn it_parses_break_settings() {
+        let config = Config::from(
+            r#"
+[rules.breaks]
+enabled = true
+minimum_minutes = 10
+thresholds = [{ work_hours = 4.5, break_minutes = 20 }]
+"#,
+        )
+        .unwrap();
+
+        let breaks = &config.rules().breaks;
+        assert!(breaks.enabled);
+        assert!(!Config::default().rules().breaks.enabled);
+        assert_eq!(breaks.minimum_break(), Duration::minutes(10));
+        assert_eq!(breaks.required_break(hours(5.0)), Duration::minutes(20));
+    }
+
+    #[test]
+    fn it_parses_working_time_settings() {
+        let config = Config::from(
+            r#"
+[rules.working_time]
+enabled = true
+max_weekly_hours = 48.0
+"#,
+        )
+        .unwrap();
+
+        let working_time = &config.rules().working_time;
+        assert!(working_time.enabled);
+        assert_eq!(working_time.max_daily(), hours(10.0));
+        assert_eq!(working_time.max_weekly(), Some(hours(48.0)));
+        assert!(!Config::default().rules().working_time.enabled);
+    }
+
+    #[test]
+    fn it_parses_rest_settings() {
+        let config = Config::from(
+            r#"
+[rules.rest]
+enabled = true
+exempt_projects = ["On-call"]
+"#,
+        )
+        .unwrap();
+
+        let rest = &config.rules().rest;
+        assert!(rest.enabled);
+        assert_eq!(rest.minimum_rest(), hours(11.0));
+        assert_eq!(rest.exempt_projects, vec!["On-call".to_string()]);
+        assert!(!Config::default().rules().rest.enabled);
+    }
+
+    #[test]
+    fn it_parses_gap_settings() {
+        let config = Config::from(
+            r#"
+[rules.gaps]
+enabled = true
+working_hours = { start = "09:00", end = "17:30" }
+"#,
+        )
+        .unwrap();
+
+        let gaps = &config.rules().gaps;
+        assert!(gaps.enabled);
+        assert_eq!(gaps.minimum_gap(), Duration::minutes(15));
+        assert_eq!(gaps.working_hours.start, NaiveTime::from_hms(9, 0, 0));
+        assert_eq!(gaps.working_hours.end, NaiveTime::from_hms(17, 30, 0));
+    }
+
+    #[test]
+    fn it_parses_schedules() {
+        let config = Config::from(
+            r#"
+[[schedules]]
+from = "2020-01-01"
+monday = 8.0
+friday = 4.5
+"#,
+        )
+        .unwrap();
+
+        let schedule = &config.schedules()[0];
+        assert_eq!(schedule.from, Some(NaiveDate::from_ymd(2020, 1, 1)));
+        assert_eq!(schedule.hours_on(Weekday::Mon), Duration::hours(8));
+        assert_eq!(schedule.hours_on(Weekday::Fri), Duration::minutes(270));
+        assert_eq!(schedule.hours_on(Weekday::Sun), Duration::zero());
+    }
+
+    #[test]
+    fn it_rounds_times_to_the_grid() {
+        let config = Config::from(
+            r#"
+[[rules.rounding]]
+project = "Timeval"
+minutes = 15
+direction = "up"
+
+[[rules.rounding]]
+minutes = 30
+"#,
+        )
+        .unwrap();
+        let rounding = &config.rules().rounding;
+        let time = Local.ymd(2020, 1, 12).and_hms(8, 7, 0);
+
+        assert_eq!(rounding[0].project.as_deref(), Some("Timeval"));
+        assert_eq!(
+            rounding[0].round(&time),
+            Local.ymd(2020, 1, 12).and_hms(8, 15, 0)
+        );
+        assert_eq!(rounding[1].direction, RoundingDirection::Nearest);
+        assert_eq!(
+            rounding[1].round(&time),
+            Local.ymd(2020, 1, 12).and_hms(8, 0, 0)
+        );
+        assert!(!rounding[1].is_on_grid(&time));
+        assert!(rounding[1].is_on_grid(&Local.ymd(2020, 1, 12).and_hms(8, 30, 0)));
+    }
+}
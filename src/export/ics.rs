@@ -0,0 +1,175 @@
+use crate::harvest_api::ledger::fingerprint;
+use crate::projects_and_tasks::named::Named;
+use crate::validation::activity::Activity;
+use chrono::{DateTime, FixedOffset, Local, Utc};
+use std::collections::BTreeSet;
+
+const MAX_LINE_LENGTH: usize = 75;
+
+pub fn ics(activities: &[Activity], now: DateTime<Utc>) -> String {
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        "PRODID:-//timing//timesheet export//EN".to_string(),
+        "CALSCALE:GREGORIAN".to_string(),
+    ];
+    let offsets = activities
+        .iter()
+        .flat_map(|activity| {
+            vec![
+                offset(activity.start().date()),
+                offset(activity.end().date()),
+            ]
+        })
+        .collect::<BTreeSet<_>>();
+    for offset in offsets {
+        lines.extend(time_zone(offset));
+    }
+    for activity in activities {
+        lines.extend(event(activity, now));
+    }
+    lines.push("END:VCALENDAR".to_string());
+
+    lines
+        .iter()
+        .map(|line| fold(line))
+        .collect::<Vec<_>>()
+        .concat()
+}
+
+fn event(activity: &Activity, now: DateTime<Utc>) -> Vec<String> {
+    vec![
+        "BEGIN:VEVENT".to_string(),
+        format!("UID:{}@timing", fingerprint(activity)),
+        format!("DTSTAMP:{}", now.format("%Y%m%dT%H%M%SZ")),
+        format!("DTSTART;{}", date_time(activity.start().date())),
+        format!("DTEND;{}", date_time(activity.end().date())),
+        format!(
+            "SUMMARY:{}",
+            text(&format!(
+                "{} / {}",
+                activity.project().name(),
+                activity.task().name()
+            ))
+        ),
+        format!("DESCRIPTION:{}", text(activity.description().text())),
+        "END:VEVENT".to_string(),
+    ]
+}
+
+fn offset(date: &DateTime<Local>) -> i32 {
+    date.offset().local_minus_utc()
+}
+
+fn utc_offset(offset: i32) -> String {
+    FixedOffset::east(offset).to_string().replace(':', "")
+}
+
+fn time_zone_id(offset: i32) -> String {
+    format!("UTC{}", utc_offset(offset))
+}
+
+fn time_zone(offset: i32) -> Vec<String> {
+    let utc_offset = utc_offset(offset);
+    vec![
+        "BEGIN:VTIMEZONE".to_string(),
+        format!("TZID:{}", time_zone_id(offset)),
+        "BEGIN:STANDARD".to_string(),
+        "DTSTART:19700101T000000".to_string(),
+        format!("TZOFFSETFROM:{}", utc_offset),
+        format!("TZOFFSETTO:{}", utc_offset),
+        "END:STANDARD".to_string(),
+        "END:VTIMEZONE".to_string(),
+    ]
+}
+
+fn date_time(date: &DateTime<Local>) -> String {
+    format!(
+        "TZID={}:{}",
+        time_zone_id(offset(date)),
+        date.format("%Y%m%dT%H%M%S")
+    )
+}
+
+fn text(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n")
+}
+
+fn fold(line: &str) -> String {
+    let mut folded = String::new();
+    let mut length = 0;
+    for character in line.chars() {
+        if length + character.len_utf8() > MAX_LINE_LENGTH {
+            folded.push_str("\r\n ");
+            length = 1;
+        }
+        folded.push(character);
+        length += character.len_utf8();
+    }
+    folded.push_str("\r\n");
+    folded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::projects_and_tasks::task::TaskBuilder;
+    use crate::test_fixtures::ActivityBuilder;
+    use chrono::prelude::*;
+
+    fn activity(description: &str) -> Activity {
+        ActivityBuilder::new()
+            .with_project_name("Timeval")
+            .with_task(TaskBuilder::new().with_name("Build".to_string()).build())
+            .with_description(description)
+            .build()
+    }
+
+    #[test]
+    fn it_writes_an_event_per_activity_in_its_time_zone() {
+        let activity = activity("Improve Readme; add examples, too");
+        let zone = time_zone_id(offset(activity.start().date()));
+
+        let ics = ics(&[activity], Utc.ymd(2020, 1, 13).and_hms(9, 0, 0));
+        let lines = ics.split("\r\n").collect::<Vec<_>>();
+
+        assert_eq!(lines.first(), Some(&"BEGIN:VCALENDAR"));
+        assert!(lines.contains(&format!("TZID:{}", zone).as_str()));
+        assert!(lines.contains(&format!("DTSTART;TZID={}:20200112T080000", zone).as_str()));
+        assert!(lines.contains(&format!("DTEND;TZID={}:20200112T083000", zone).as_str()));
+        assert!(lines.contains(&"DTSTAMP:20200113T090000Z"));
+        assert!(lines.contains(&"SUMMARY:Timeval / Build"));
+        assert!(lines.contains(&r"DESCRIPTION:Improve Readme\; add examples\, too"));
+        assert_eq!(
+            lines.iter().filter(|line| **line == "BEGIN:VEVENT").count(),
+            1
+        );
+    }
+
+    #[test]
+    fn it_names_time_zones_without_colons() {
+        assert_eq!(time_zone_id(3600), "UTC+0100");
+        assert_eq!(time_zone_id(-9000), "UTC-0230");
+        assert_eq!(time_zone(0)[1], "TZID:UTC+0000");
+        assert_eq!(time_zone(0)[4], "TZOFFSETFROM:+0000");
+        assert_eq!(
+            date_time(&Local.ymd(2020, 1, 12).and_hms(8, 0, 0))
+                .matches(':')
+                .count(),
+            1
+        );
+    }
+
+    #[test]
+    fn it_folds_long_lines() {
+        let folded = fold(&"x".repeat(100));
+
+        assert_eq!(
+            folded,
+            format!("{}\r\n {}\r\n", "x".repeat(75), "x".repeat(25))
+        );
+    }
+}
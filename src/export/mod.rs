@@ -0,0 +1,2 @@
+pub mod ics;
+pub mod timeclock;
@@ -0,0 +1,69 @@
+use crate::projects_and_tasks::named::Named;
+use crate::validation::activity::Activity;
+
+const DATE_TIME_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+pub fn timeclock(activities: &[Activity]) -> String {
+    activities
+        .iter()
+        .map(|activity| {
+            let account = format!(
+                "{}:{}",
+                account_name(activity.project().name()),
+                account_name(activity.task().name())
+            );
+            let description = activity.description().text();
+            let clock_in = if description.is_empty() {
+                account
+            } else {
+                format!("{}  {}", account, description)
+            };
+            format!(
+                "i {} {}\no {}\n",
+                activity.start().date().format(DATE_TIME_FORMAT),
+                clock_in,
+                activity.end().date().format(DATE_TIME_FORMAT)
+            )
+        })
+        .collect()
+}
+
+fn account_name(name: &str) -> String {
+    name.replace(':', "-")
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::projects_and_tasks::task::TaskBuilder;
+    use crate::test_fixtures::ActivityBuilder;
+
+    fn activity(description: &str) -> Activity {
+        ActivityBuilder::new()
+            .with_project_name("My Timeval project")
+            .with_task(
+                TaskBuilder::new()
+                    .with_name("Build: docs".to_string())
+                    .build(),
+            )
+            .with_description(description)
+            .build()
+    }
+
+    #[test]
+    fn it_writes_clock_in_and_clock_out_lines() {
+        let timeclock = timeclock(&[activity("Improve Readme"), activity("")]);
+
+        assert_eq!(
+            timeclock,
+            "i 2020-01-12 08:00:00 My Timeval project:Build- docs  Improve Readme
+o 2020-01-12 08:30:00
+i 2020-01-12 08:00:00 My Timeval project:Build- docs
+o 2020-01-12 08:30:00
+"
+        );
+    }
+}
@@ -0,0 +1,176 @@
+use crate::parser::fields::Fields;
+use chrono::NaiveDateTime;
+use unicode_width::UnicodeWidthStr;
+
+const DATE_TIME_FORMAT: &str = "%Y-%m-%dT%H:%M:%S%.f";
+
+enum Row {
+    Cells(Vec<String>),
+    Hline,
+}
+
+pub fn align(text: &str, description_takes_rest: bool) -> String {
+    let lines = text.split('\n').collect::<Vec<_>>();
+    let rows = lines
+        .iter()
+        .map(|line| row(line, description_takes_rest))
+        .collect::<Vec<_>>();
+
+    let mut aligned = vec![];
+    let mut index = 0;
+    while index < lines.len() {
+        let table = rows[index..]
+            .iter()
+            .map_while(Option::as_ref)
+            .collect::<Vec<_>>();
+        if table.is_empty() {
+            aligned.push(lines[index].trim_end().to_string());
+            index += 1;
+            continue;
+        }
+        let widths = widths(&table);
+        aligned.extend(table.iter().map(|row| match row {
+            Row::Cells(cells) => format_row(cells, &widths),
+            Row::Hline => format_hline(&widths),
+        }));
+        index += table.len();
+    }
+    aligned.join("\n")
+}
+
+fn row(line: &str, description_takes_rest: bool) -> Option<Row> {
+    let line = line.trim();
+    if !line.starts_with('|') {
+        return None;
+    }
+    if line.starts_with("|-") {
+        return Some(Row::Hline);
+    }
+    let fields = match Fields::from_row(line, description_takes_rest) {
+        Ok(fields) => fields,
+        Err(_) => return Some(Row::Cells(cells(line))),
+    };
+    Some(Row::Cells(
+        vec![
+            date_time(&fields.start),
+            date_time(&fields.end),
+            fields.project,
+            fields.task,
+            fields.description,
+        ]
+        .into_iter()
+        .map(|cell| cell.replace('|', "\\|"))
+        .collect(),
+    ))
+}
+
+fn cells(line: &str) -> Vec<String> {
+    let line = line.strip_prefix('|').unwrap_or(line);
+    let line = match line.strip_suffix('|') {
+        Some(inner) if !inner.ends_with('\\') => inner,
+        _ => line,
+    };
+    let mut cells = vec![];
+    let mut cell = String::new();
+    let mut characters = line.chars().peekable();
+    while let Some(character) = characters.next() {
+        match character {
+            '\\' if characters.peek() == Some(&'|') => {
+                cell.push(character);
+                cell.extend(characters.next());
+            }
+            '|' => cells.push(std::mem::take(&mut cell).trim().to_string()),
+            _ => cell.push(character),
+        }
+    }
+    cells.push(cell.trim().to_string());
+    cells
+}
+
+fn widths(table: &[&Row]) -> Vec<usize> {
+    let mut widths: Vec<usize> = vec![];
+    for row in table {
+        if let Row::Cells(cells) = row {
+            for (column, cell) in cells.iter().enumerate() {
+                match widths.get_mut(column) {
+                    Some(width) => *width = (*width).max(cell.width()),
+                    None => widths.push(cell.width()),
+                }
+            }
+        }
+    }
+    widths
+}
+
+fn date_time(string: &str) -> String {
+    string
+        .parse::<NaiveDateTime>()
+        .map(|date_time| date_time.format(DATE_TIME_FORMAT).to_string())
+        .unwrap_or_else(|_| string.to_string())
+}
+
+fn format_row(cells: &[String], widths: &[usize]) -> String {
+    let cells = widths
+        .iter()
+        .enumerate()
+        .map(|(column, width)| {
+            let cell = cells.get(column).map(String::as_str).unwrap_or_default();
+            format!("{}{}", cell, " ".repeat(width - cell.width()))
+        })
+        .collect::<Vec<_>>();
+    format!("| {} |", cells.join(" | "))
+}
+
+fn format_hline(widths: &[usize]) -> String {
+    let dashes = widths
+        .iter()
+        .map(|width| "-".repeat(width + 2))
+        .collect::<Vec<_>>();
+    format!("|{}|", dashes.join("+"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_aligns_columns_of_each_table() {
+        let text = "* Week 2  \n|2020-01-12T08:00:00|2020-01-12T08:30:00.000|Timeval|Build|Improve Readme \\| docs|\n   | 2020-01-12T08:30:00 | 2020-01-12T10:30:00.750 | Training | Self-Study | Übung für 読む |  \n\n| a wrong time | 2020-01-12T12:30:00 | Training | Self-Study | Read |\n";
+
+        let aligned = align(text, false);
+
+        assert_eq!(
+            aligned,
+            "* Week 2
+| 2020-01-12T08:00:00 | 2020-01-12T08:30:00     | Timeval  | Build      | Improve Readme \\| docs |
+| 2020-01-12T08:30:00 | 2020-01-12T10:30:00.750 | Training | Self-Study | Übung für 読む         |
+
+| a wrong time | 2020-01-12T12:30:00 | Training | Self-Study | Read |
+"
+        );
+        assert_eq!(align(&aligned, false), aligned);
+    }
+
+    #[test]
+    fn it_keeps_lines_that_are_no_activities() {
+        let text = "| Start | End |\n#+TBLFM: $1";
+
+        assert_eq!(align(text, false), text);
+    }
+
+    #[test]
+    fn it_aligns_headers_and_hlines_with_the_rows_below() {
+        let text = "| Start | End | Project |\n|---+---|\n|2020-01-12T08:00:00|2020-01-12T08:30:00|Timeval|Build|Readme|\n| 2020-01-12 | Other \\| row |";
+
+        let aligned = align(text, false);
+
+        assert_eq!(
+            aligned,
+            "| Start               | End                 | Project |       |        |
+|---------------------+---------------------+---------+-------+--------|
+| 2020-01-12T08:00:00 | 2020-01-12T08:30:00 | Timeval | Build | Readme |
+| 2020-01-12          | Other \\| row        |         |       |        |"
+        );
+        assert_eq!(align(&aligned, false), aligned);
+    }
+}
@@ -0,0 +1,230 @@
+use crate::config::BreakConfig;
+use crate::fix::{shift, Fix, Slot};
+use crate::line_error::LineError;
+use crate::rules::rounding::Roundings;
+use chrono::Duration;
+use std::cmp::max;
+
+pub struct BreakFix {
+    config: BreakConfig,
+    roundings: Option<Roundings>,
+}
+
+impl BreakFix {
+    pub fn new(config: BreakConfig) -> Self {
+        Self {
+            config,
+            roundings: None,
+        }
+    }
+
+    pub fn with_roundings(mut self, roundings: Roundings) -> Self {
+        self.roundings = Some(roundings);
+        self
+    }
+
+    fn shift(&self, slots: &mut [Slot], by: Duration) -> Duration {
+        let grid = slots
+            .iter()
+            .filter_map(|slot| {
+                self.roundings
+                    .as_ref()?
+                    .for_project(&slot.project)
+                    .map(|rounding| rounding.granularity())
+            })
+            .fold(1, lcm);
+        let by = Duration::seconds((by.num_seconds() + grid - 1) / grid * grid);
+        shift(slots, by);
+        by
+    }
+
+    fn fix_day(&self, slots: &mut [Slot]) {
+        if slots.len() < 2 {
+            return;
+        }
+        let minimum = self.config.minimum_break();
+        let worked = slots
+            .iter()
+            .fold(Duration::zero(), |worked, slot| worked + slot.duration());
+        let required = self.config.required_break(worked);
+
+        let mut breaks = Duration::zero();
+        let mut continuous = Duration::zero();
+        let mut last_break = None;
+        for index in 0..slots.len() {
+            let duration = slots[index].duration();
+            if index > 0 {
+                let mut gap = slots[index].start - slots[index - 1].end;
+                if gap < minimum && continuous + duration > self.config.max_continuous_work() {
+                    let wanted = max(minimum, required - breaks);
+                    gap = gap + self.shift(&mut slots[index..], wanted - gap);
+                }
+                if gap >= minimum {
+                    breaks = breaks + gap;
+                    continuous = Duration::zero();
+                    last_break = Some(index);
+                }
+            }
+            continuous = continuous + duration;
+        }
+
+        if breaks < required {
+            let index = last_break.unwrap_or_else(|| middle(slots, worked));
+            let gap = slots[index].start - slots[index - 1].end;
+            let extra = if gap >= minimum {
+                required - breaks
+            } else {
+                max(minimum, required - breaks) - gap
+            };
+            self.shift(&mut slots[index..], extra);
+        }
+    }
+}
+
+impl Fix for BreakFix {
+    fn fix(&self, slots: &mut [Slot]) -> Vec<LineError> {
+        let mut index = 0;
+        while index < slots.len() {
+            let day = slots[index].start.date();
+            let count = slots[index..]
+                .iter()
+                .take_while(|slot| slot.start.date() == day)
+                .count();
+            self.fix_day(&mut slots[index..index + count]);
+            index += count;
+        }
+        vec![]
+    }
+}
+
+fn lcm(a: i64, b: i64) -> i64 {
+    let (mut x, mut y) = (a, b);
+    while y != 0 {
+        (x, y) = (y, x % y);
+    }
+    a / x * b
+}
+
+fn middle(slots: &[Slot], worked: Duration) -> usize {
+    let mut before = Duration::zero();
+    for (index, slot) in slots.iter().enumerate().skip(1) {
+        before = before + slots[index - 1].duration();
+        if before + slot.duration() / 2 >= worked / 2 {
+            return index;
+        }
+    }
+    slots.len() - 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{RoundingConfig, RoundingDirection};
+    use crate::projects_and_tasks::projects::ProjectsBuilder;
+    use crate::rules::break_rule::check_day;
+    use crate::test_fixtures::slot;
+
+    fn times(slots: &[Slot]) -> Vec<(String, String)> {
+        slots
+            .iter()
+            .map(|slot| {
+                (
+                    slot.start.format("%H:%M").to_string(),
+                    slot.end.format("%H:%M").to_string(),
+                )
+            })
+            .collect()
+    }
+
+    fn is_valid(slots: &[Slot]) -> bool {
+        let intervals = slots
+            .iter()
+            .map(|slot| (slot.start, slot.end))
+            .collect::<Vec<_>>();
+        check_day(&intervals, &BreakConfig::default()).is_empty()
+    }
+
+    #[test]
+    fn it_inserts_a_break_before_working_too_long_and_shifts_later_entries() {
+        let mut slots = vec![
+            slot(0, (8, 0), (12, 0)),
+            slot(1, (12, 0), (14, 30)),
+            slot(2, (14, 30), (15, 0)),
+        ];
+
+        BreakFix::new(BreakConfig::default()).fix(&mut slots);
+
+        assert_eq!(
+            times(&slots),
+            vec![
+                ("08:00".to_string(), "12:00".to_string()),
+                ("12:30".to_string(), "15:00".to_string()),
+                ("15:00".to_string(), "15:30".to_string()),
+            ]
+        );
+        assert!(!slots[0].is_changed());
+        assert!(slots[1].is_changed());
+        assert!(is_valid(&slots));
+    }
+
+    #[test]
+    fn it_extends_existing_breaks_to_the_required_length() {
+        let mut slots = vec![
+            slot(0, (7, 0), (11, 0)),
+            slot(1, (11, 15), (15, 0)),
+            slot(2, (15, 20), (17, 0)),
+        ];
+
+        BreakFix::new(BreakConfig::default()).fix(&mut slots);
+
+        assert_eq!(
+            times(&slots),
+            vec![
+                ("07:00".to_string(), "11:00".to_string()),
+                ("11:15".to_string(), "15:00".to_string()),
+                ("15:30".to_string(), "17:10".to_string()),
+            ]
+        );
+        assert!(is_valid(&slots));
+    }
+
+    #[test]
+    fn it_leaves_valid_days_untouched() {
+        let mut slots = vec![slot(0, (8, 0), (12, 0)), slot(1, (12, 0), (13, 0))];
+
+        BreakFix::new(BreakConfig::default()).fix(&mut slots);
+
+        assert!(slots.iter().all(|slot| !slot.is_changed()));
+    }
+
+    #[test]
+    fn it_shifts_by_multiples_of_the_rounding_grid() {
+        let mut slots = vec![
+            slot(0, (8, 0), (12, 0)),
+            slot(1, (12, 0), (14, 20)),
+            slot(2, (14, 20), (15, 0)),
+        ];
+        let roundings = Roundings::new(
+            &[RoundingConfig {
+                project: None,
+                minutes: 20,
+                direction: RoundingDirection::Nearest,
+            }],
+            &ProjectsBuilder::new().build(),
+        );
+
+        BreakFix::new(BreakConfig::default())
+            .with_roundings(roundings)
+            .fix(&mut slots);
+
+        assert_eq!(
+            times(&slots),
+            vec![
+                ("08:00".to_string(), "12:00".to_string()),
+                ("12:40".to_string(), "15:00".to_string()),
+                ("15:00".to_string(), "15:40".to_string()),
+            ]
+        );
+        assert!(is_valid(&slots));
+    }
+}
@@ -0,0 +1,60 @@
+pub fn diff(file: &str, old: &str, new: &str) -> String {
+    let old_lines = old.split('\n').collect::<Vec<_>>();
+    let new_lines = new.split('\n').collect::<Vec<_>>();
+    let changed = old_lines
+        .iter()
+        .zip(&new_lines)
+        .enumerate()
+        .filter(|(_no, (old, new))| old != new)
+        .map(|(no, _)| no)
+        .collect::<Vec<_>>();
+    if changed.is_empty() {
+        return String::new();
+    }
+
+    let mut diff = format!("--- a/{}\n+++ b/{}\n", file, file);
+    let mut index = 0;
+    while index < changed.len() {
+        let first = changed[index];
+        let count = changed[index..]
+            .iter()
+            .enumerate()
+            .take_while(|(offset, no)| **no == first + offset)
+            .count();
+        diff.push_str(&format!(
+            "@@ -{},{} +{},{} @@\n",
+            first + 1,
+            count,
+            first + 1,
+            count
+        ));
+        for line in &old_lines[first..first + count] {
+            diff.push_str(&format!("-{}\n", line));
+        }
+        for line in &new_lines[first..first + count] {
+            diff.push_str(&format!("+{}\n", line));
+        }
+        index += count;
+    }
+    diff
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_shows_changed_lines_in_hunks() {
+        let diff = diff("t.org", "a\nb\nc\nd\n", "a\nB\nC\nd\n");
+
+        assert_eq!(
+            diff,
+            "--- a/t.org\n+++ b/t.org\n@@ -2,2 +2,2 @@\n-b\n-c\n+B\n+C\n"
+        );
+    }
+
+    #[test]
+    fn it_is_empty_without_changes() {
+        assert_eq!(diff("t.org", "a\n", "a\n"), "");
+    }
+}
@@ -0,0 +1,25 @@
+use std::fmt;
+
+#[derive(Debug, PartialEq)]
+pub enum FixError {
+    TimesNotFound,
+    NotRounded,
+}
+
+impl fmt::Display for FixError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let error_name = "FixError";
+        match &self {
+            Self::TimesNotFound => write!(
+                f,
+                "{:<10} | {}: Start and end cells not found, line left untouched",
+                "Line", error_name
+            ),
+            Self::NotRounded => write!(
+                f,
+                "{:<10} | {}: Times can't be rounded without overlapping a neighbour",
+                "Rounding", error_name
+            ),
+        }
+    }
+}
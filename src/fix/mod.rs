@@ -0,0 +1,79 @@
+pub mod align;
+pub mod break_fix;
+pub mod diff;
+pub mod fix_error;
+pub mod normalize;
+pub mod normalize_error;
+pub mod rewrite;
+pub mod rounding_fix;
+
+use crate::line_error::LineError;
+use crate::parser::fields::Fields;
+use crate::validation::activity::Activity;
+use chrono::{DateTime, Duration, Local};
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Slot {
+    pub line: usize,
+    pub start: DateTime<Local>,
+    pub end: DateTime<Local>,
+    pub project: String,
+    original_start: DateTime<Local>,
+    original_end: DateTime<Local>,
+    start_text: String,
+    end_text: String,
+}
+
+impl Slot {
+    pub fn new(line: usize, fields: &Fields, activity: &Activity) -> Self {
+        Self {
+            line,
+            start: *activity.start().date(),
+            end: *activity.end().date(),
+            project: activity.project().name().to_string(),
+            original_start: *activity.start().date(),
+            original_end: *activity.end().date(),
+            start_text: fields.start.clone(),
+            end_text: fields.end.clone(),
+        }
+    }
+
+    pub fn is_changed(&self) -> bool {
+        self.start != self.original_start || self.end != self.original_end
+    }
+
+    pub fn duration(&self) -> Duration {
+        self.end - self.start
+    }
+}
+
+pub fn shift(slots: &mut [Slot], by: Duration) {
+    for slot in slots {
+        slot.start = slot.start + by;
+        slot.end = slot.end + by;
+    }
+}
+
+pub trait Fix {
+    fn fix(&self, slots: &mut [Slot]) -> Vec<LineError>;
+}
+
+#[derive(Default)]
+pub struct Fixes {
+    fixes: Vec<Box<dyn Fix>>,
+}
+
+impl Fixes {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with(mut self, fix: impl Fix + 'static) -> Self {
+        self.fixes.push(Box::new(fix));
+        self
+    }
+
+    pub fn apply(&self, slots: &mut [Slot]) -> Vec<LineError> {
+        self.fixes.iter().flat_map(|fix| fix.fix(slots)).collect()
+    }
+}
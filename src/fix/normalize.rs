@@ -0,0 +1,211 @@
+use crate::fix::{
+    normalize_error::NormalizeError,
+    rewrite::{replace_cells, Column},
+};
+use crate::line_error::LineError;
+use crate::parser::input_format::InputFormat;
+use crate::projects_and_tasks::{named::Named, project::Project, projects::Projects, task::Task};
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Reference {
+    Names,
+    Ids,
+}
+
+pub fn normalize(
+    text: &str,
+    format: &InputFormat,
+    projects: &Projects,
+    reference: Reference,
+) -> (String, Vec<LineError>) {
+    let positions = format.column_positions(text);
+    let mut lines = text.split('\n').map(String::from).collect::<Vec<_>>();
+    let mut errors = vec![];
+    for (no, fields) in format.records(text, projects) {
+        let fields = match fields {
+            Ok(fields) => fields,
+            Err(error) => {
+                errors.extend(error.at_line(no));
+                continue;
+            }
+        };
+        let (project, task) = match projects.get_project_with_task(&fields.project, &fields.task) {
+            Ok(project_and_task) => project_and_task,
+            Err(error) => {
+                errors.push(LineError::new(no, error.to_string()));
+                continue;
+            }
+        };
+        let replaced = references(&project, &task, reference)
+            .and_then(|references| unambiguous(projects, &project, &task, references))
+            .and_then(|(new_project, new_task)| {
+                let cells = [
+                    (
+                        Column::Project,
+                        fields.project.as_str(),
+                        new_project.as_str(),
+                    ),
+                    (Column::Task, fields.task.as_str(), new_task.as_str()),
+                ];
+                match replace_cells(&mut lines[no], format, &positions, &cells) {
+                    true => Ok(()),
+                    false => Err(NormalizeError::CellsNotFound),
+                }
+            });
+        if let Err(error) = replaced {
+            errors.push(LineError::new(no, error.to_string()));
+        }
+    }
+    (lines.join("\n"), errors)
+}
+
+fn references(
+    project: &Project,
+    task: &Task,
+    reference: Reference,
+) -> Result<(String, String), NormalizeError> {
+    match reference {
+        Reference::Names => Ok((project.name().to_string(), task.name().to_string())),
+        Reference::Ids => {
+            let project_id = project
+                .id()
+                .ok_or_else(|| NormalizeError::ProjectWithoutId(project.name().to_string()))?;
+            let task_id = task
+                .id()
+                .ok_or_else(|| NormalizeError::TaskWithoutId(task.name().to_string()))?;
+            Ok((format!("#{}", project_id), format!("#{}", task_id)))
+        }
+    }
+}
+
+fn unambiguous(
+    projects: &Projects,
+    project: &Project,
+    task: &Task,
+    (new_project, new_task): (String, String),
+) -> Result<(String, String), NormalizeError> {
+    match projects.get_project_with_task(&new_project, &new_task) {
+        Ok(resolved) if resolved == (project.clone(), task.clone()) => Ok((new_project, new_task)),
+        _ => Err(NormalizeError::Ambiguous(new_project, new_task)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{CsvColumns, TableConfig};
+    use crate::projects_and_tasks::{
+        project::ProjectWithTasksBuilder, projects::ProjectsBuilder, task::TaskBuilder,
+        tasks::TasksBuilder,
+    };
+
+    fn projects() -> Projects {
+        ProjectsBuilder::new()
+            .with_projects(vec![ProjectWithTasksBuilder::new()
+                .with_id(1)
+                .with_name("My Timeval project".to_string())
+                .with_aliases(vec!["TV".to_string()])
+                .with_tasks(
+                    TasksBuilder::new()
+                        .with_tasks(vec![TaskBuilder::new()
+                            .with_optional_id(None)
+                            .with_name("Build stuff".to_string())
+                            .build()])
+                        .build(),
+                )
+                .build()])
+            .build()
+    }
+
+    #[test]
+    fn it_writes_full_names_and_keeps_the_layout() {
+        let text = "* Notes\n| 2020-01-12T08:00:00 | 2020-01-12T08:30:00 | tv   | build   | Build |\n| 2020-01-12T08:30:00 | 2020-01-12T09:00:00 | Other | build | Build |\n";
+
+        let (normalized, errors) = normalize(
+            text,
+            &InputFormat::Org(TableConfig::default()),
+            &projects(),
+            Reference::Names,
+        );
+
+        assert_eq!(
+            normalized,
+            "* Notes\n| 2020-01-12T08:00:00 | 2020-01-12T08:30:00 | My Timeval project   | Build stuff   | Build |\n| 2020-01-12T08:30:00 | 2020-01-12T09:00:00 | Other | build | Build |\n"
+        );
+        assert_eq!(
+            errors
+                .iter()
+                .map(LineError::line_number)
+                .collect::<Vec<_>>(),
+            vec![0, 2]
+        );
+    }
+
+    #[test]
+    fn it_reports_references_without_ids() {
+        let text = "Start,End,Project,Task,Description\n2020-01-12T08:00:00,2020-01-12T08:30:00,tv,build,Build";
+
+        let (normalized, errors) = normalize(
+            text,
+            &InputFormat::Csv {
+                delimiter: b',',
+                columns: CsvColumns::default(),
+            },
+            &projects(),
+            Reference::Ids,
+        );
+
+        assert_eq!(normalized, text);
+        assert_eq!(
+            errors,
+            vec![LineError::new(
+                1,
+                NormalizeError::TaskWithoutId("Build stuff".to_string()).to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn it_reports_names_that_would_become_ambiguous_and_finds_escaped_cells() {
+        let project = |name: &str, aliases: Vec<String>| {
+            ProjectWithTasksBuilder::new()
+                .with_name(name.to_string())
+                .with_aliases(aliases)
+                .with_tasks(
+                    TasksBuilder::new()
+                        .with_tasks(vec![TaskBuilder::new()
+                            .with_name("Build stuff".to_string())
+                            .build()])
+                        .build(),
+                )
+                .build()
+        };
+        let projects = ProjectsBuilder::new()
+            .with_projects(vec![
+                project("Timeval", vec!["core".to_string()]),
+                project("Timeval | Support", vec![]),
+            ])
+            .build();
+        let text = "| 2020-01-12T08:00:00 | 2020-01-12T08:30:00 | core | build | Build |\n| 2020-01-12T08:30:00 | 2020-01-12T09:00:00 | val \\| sup | build | Build |";
+
+        let (normalized, errors) = normalize(
+            text,
+            &InputFormat::Org(TableConfig::default()),
+            &projects,
+            Reference::Names,
+        );
+
+        assert_eq!(
+            normalized,
+            "| 2020-01-12T08:00:00 | 2020-01-12T08:30:00 | core | build | Build |\n| 2020-01-12T08:30:00 | 2020-01-12T09:00:00 | Timeval \\| Support | Build stuff | Build |"
+        );
+        assert_eq!(
+            errors,
+            vec![LineError::new(
+                0,
+                NormalizeError::Ambiguous("Timeval".to_string(), "Build stuff".to_string())
+                    .to_string()
+            )]
+        );
+    }
+}
@@ -0,0 +1,37 @@
+use std::fmt;
+
+#[derive(Debug, PartialEq)]
+pub enum NormalizeError {
+    ProjectWithoutId(String),
+    TaskWithoutId(String),
+    Ambiguous(String, String),
+    CellsNotFound,
+}
+
+impl fmt::Display for NormalizeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let error_name = "NormalizeError";
+        match &self {
+            Self::ProjectWithoutId(name) => write!(
+                f,
+                "{:<10} | {}: Project '{}' has no id",
+                "Project", error_name, name
+            ),
+            Self::TaskWithoutId(name) => write!(
+                f,
+                "{:<10} | {}: Task '{}' has no id",
+                "Task", error_name, name
+            ),
+            Self::Ambiguous(project, task) => write!(
+                f,
+                "{:<10} | {}: '{}' and '{}' don't name this project and task unambiguously",
+                "Reference", error_name, project, task
+            ),
+            Self::CellsNotFound => write!(
+                f,
+                "{:<10} | {}: Project and task cells not found, line left untouched",
+                "Line", error_name
+            ),
+        }
+    }
+}
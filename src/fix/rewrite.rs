@@ -0,0 +1,139 @@
+use crate::fix::{fix_error::FixError, Slot};
+use crate::line_error::LineError;
+use crate::parser::input_format::InputFormat;
+
+const DATE_TIME_FORMAT: &str = "%Y-%m-%dT%H:%M:%S";
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Column {
+    Start,
+    End,
+    Project,
+    Task,
+}
+
+pub fn rewrite(text: &str, format: &InputFormat, slots: &[Slot]) -> (String, Vec<LineError>) {
+    let positions = format.column_positions(text);
+    let mut lines = text.split('\n').map(String::from).collect::<Vec<_>>();
+    let mut errors = vec![];
+    for slot in slots.iter().filter(|slot| slot.is_changed()) {
+        let start = slot.start.format(DATE_TIME_FORMAT).to_string();
+        let end = slot.end.format(DATE_TIME_FORMAT).to_string();
+        let replaced = replace_cells(
+            &mut lines[slot.line],
+            format,
+            &positions,
+            &[
+                (Column::Start, &slot.start_text, &start),
+                (Column::End, &slot.end_text, &end),
+            ],
+        );
+        if !replaced {
+            errors.push(LineError::new(
+                slot.line,
+                FixError::TimesNotFound.to_string(),
+            ));
+        }
+    }
+    (lines.join("\n"), errors)
+}
+
+pub fn replace_cells(
+    line: &mut String,
+    format: &InputFormat,
+    positions: &[usize],
+    cells: &[(Column, &str, &str)],
+) -> bool {
+    let ranges = format.cell_ranges(line);
+    let mut replacements = vec![];
+    for (column, old, new) in cells {
+        let range = positions
+            .get(*column as usize)
+            .and_then(|position| ranges.get(*position))
+            .filter(|range| format.unescape(&line[(*range).clone()]) == *old);
+        match range {
+            Some(range) => replacements.push((range.clone(), format.escape(new))),
+            None => return false,
+        }
+    }
+    replacements.sort_by_key(|(range, _new)| std::cmp::Reverse(range.start));
+    for (range, new) in replacements {
+        line.replace_range(range, &new);
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{CsvColumns, TableConfig};
+    use crate::parser::fields::Fields;
+    use crate::test_fixtures::{time, ActivityBuilder};
+    use chrono::Duration;
+
+    fn later_end(line: usize, start: &str, description: &str) -> Slot {
+        let fields = Fields {
+            start: start.to_string(),
+            end: "2020-01-12T08:00:00".to_string(),
+            project: "P".to_string(),
+            task: "T".to_string(),
+            description: description.to_string(),
+        };
+        let activity = ActivityBuilder::new()
+            .with_times(time(1, 12, 8, 0), time(1, 12, 8, 0))
+            .with_description(description)
+            .build();
+        let mut slot = Slot::new(line, &fields, &activity);
+        slot.end = slot.end + Duration::minutes(30);
+        slot
+    }
+
+    #[test]
+    fn it_rewrites_only_times_of_changed_lines() {
+        let text = "* Week\n|  2020-01-12T08:00:00 | 2020-01-12T08:00:00 | P | T | D |  \n";
+        let slot = later_end(1, "2020-01-12T08:00:00", "D");
+
+        let rewritten = rewrite(text, &InputFormat::Org(TableConfig::default()), &[slot]);
+
+        assert_eq!(
+            rewritten,
+            (
+                "* Week\n|  2020-01-12T08:00:00 | 2020-01-12T08:30:00 | P | T | D |  \n"
+                    .to_string(),
+                vec![]
+            )
+        );
+    }
+
+    #[test]
+    fn it_rewrites_the_cells_of_the_configured_columns() {
+        let text = "Description,Start,End,Project,Task\n2020-01-12T08:00:00 recap,2020-01-12T08:00:00,2020-01-12T08:00:00,P,T\n";
+        let slot = later_end(1, "2020-01-12T08:00:00", "2020-01-12T08:00:00 recap");
+        let format = InputFormat::Csv {
+            delimiter: b',',
+            columns: CsvColumns::default(),
+        };
+
+        let (rewritten, errors) = rewrite(text, &format, &[slot]);
+
+        assert_eq!(
+            rewritten,
+            "Description,Start,End,Project,Task\n2020-01-12T08:00:00 recap,2020-01-12T08:00:00,2020-01-12T08:30:00,P,T\n"
+        );
+        assert_eq!(errors, vec![]);
+    }
+
+    #[test]
+    fn it_reports_lines_whose_times_are_not_found() {
+        let text = "| 2020-01-12T08:00 | 2020-01-12T08:00:00 | P | T | D |";
+        let slot = later_end(0, "2020-01-12T08:00:00", "D");
+
+        let (rewritten, errors) = rewrite(text, &InputFormat::Org(TableConfig::default()), &[slot]);
+
+        assert_eq!(rewritten, text);
+        assert_eq!(
+            errors,
+            vec![LineError::new(0, FixError::TimesNotFound.to_string())]
+        );
+    }
+}
@@ -0,0 +1,133 @@
+use crate::fix::{fix_error::FixError, Fix, Slot};
+use crate::line_error::LineError;
+use crate::rules::rounding::Roundings;
+
+pub struct RoundingFix {
+    roundings: Roundings,
+}
+
+impl RoundingFix {
+    pub fn new(roundings: Roundings) -> Self {
+        Self { roundings }
+    }
+}
+
+impl Fix for RoundingFix {
+    fn fix(&self, slots: &mut [Slot]) -> Vec<LineError> {
+        let mut errors = vec![];
+        for index in 0..slots.len() {
+            let rounding = match self.roundings.for_project(&slots[index].project) {
+                Some(rounding) => rounding,
+                None => continue,
+            };
+            let earliest_start = index
+                .checked_sub(1)
+                .map(|previous| &slots[previous])
+                .filter(|previous| previous.original_end <= slots[index].original_start)
+                .map(|previous| previous.end);
+            let latest_end = slots
+                .get(index + 1)
+                .filter(|next| slots[index].original_end <= next.original_start)
+                .map(|next| next.original_start);
+
+            let slot = &mut slots[index];
+            let starts = [
+                rounding.round(&slot.start),
+                rounding.ceil(&slot.start),
+                rounding.floor(&slot.start),
+            ];
+            let ends = [
+                rounding.round(&slot.end),
+                rounding.floor(&slot.end),
+                rounding.ceil(&slot.end),
+            ];
+            let rounded = starts
+                .iter()
+                .filter(|start| earliest_start.is_none_or(|earliest| **start >= earliest))
+                .flat_map(|start| ends.iter().map(move |end| (*start, *end)))
+                .find(|(start, end)| start < end && latest_end.is_none_or(|latest| *end <= latest));
+            match rounded {
+                Some((start, end)) => {
+                    slot.start = start;
+                    slot.end = end;
+                }
+                None if starts[0] != slot.start || ends[0] != slot.end => {
+                    errors.push(LineError::new(slot.line, FixError::NotRounded.to_string()))
+                }
+                None => {}
+            }
+        }
+        errors
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{RoundingConfig, RoundingDirection};
+    use crate::projects_and_tasks::projects::ProjectsBuilder;
+    use crate::test_fixtures::slot;
+
+    fn fix(direction: RoundingDirection, slots: &mut [Slot]) -> Vec<LineError> {
+        let roundings = Roundings::new(
+            &[RoundingConfig {
+                project: None,
+                minutes: 15,
+                direction,
+            }],
+            &ProjectsBuilder::empty().build(),
+        );
+        RoundingFix::new(roundings).fix(slots)
+    }
+
+    fn times(slots: &[Slot]) -> Vec<String> {
+        slots
+            .iter()
+            .map(|slot| {
+                format!(
+                    "{}-{}",
+                    slot.start.format("%H:%M"),
+                    slot.end.format("%H:%M")
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn it_rounds_start_and_end_in_the_given_direction() {
+        let mut slots = vec![slot(0, (8, 2), (8, 40)), slot(1, (9, 0), (9, 50))];
+
+        fix(RoundingDirection::Nearest, &mut slots);
+
+        assert_eq!(times(&slots), vec!["08:00-08:45", "09:00-09:45"]);
+    }
+
+    #[test]
+    fn it_rounds_the_other_way_instead_of_creating_an_overlap() {
+        let mut slots = vec![slot(0, (8, 0), (8, 20)), slot(1, (8, 25), (9, 0))];
+
+        fix(RoundingDirection::Up, &mut slots);
+
+        assert_eq!(times(&slots), vec!["08:00-08:15", "08:30-09:00"]);
+    }
+
+    #[test]
+    fn it_reports_slots_it_cannot_round() {
+        let mut slots = vec![
+            slot(0, (8, 0), (8, 20)),
+            slot(1, (8, 20), (8, 25)),
+            slot(2, (8, 25), (9, 0)),
+        ];
+
+        let errors = fix(RoundingDirection::Nearest, &mut slots);
+
+        assert_eq!(
+            times(&slots),
+            vec!["08:00-08:15", "08:20-08:25", "08:30-09:00"]
+        );
+        assert_eq!(
+            errors,
+            vec![LineError::new(1, FixError::NotRounded.to_string())]
+        );
+    }
+}
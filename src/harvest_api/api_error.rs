@@ -0,0 +1,54 @@
+use std::fmt;
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum ApiError {
+    MissingSetting(&'static str),
+    Status(u16, String),
+    Transport(String),
+    InvalidResponse(String),
+    NotCached,
+    MissingId(&'static str, String),
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let error_name = "HarvestError";
+        match &self {
+            Self::MissingSetting(setting) => write!(
+                f,
+                "{}: Missing setting {}, set it in the config file or the environment",
+                error_name, setting
+            ),
+            Self::Status(status, body) => {
+                write!(
+                    f,
+                    "{}: Request failed with status {}: {}",
+                    error_name, status, body
+                )
+            }
+            Self::Transport(message) => write!(f, "{}: Request failed: {}", error_name, message),
+            Self::InvalidResponse(message) => {
+                write!(f, "{}: Invalid response: {}", error_name, message)
+            }
+            Self::NotCached => write!(
+                f,
+                "{}: No cached project assignments available while offline",
+                error_name
+            ),
+            Self::MissingId(kind, name) => {
+                write!(f, "{}: {} '{}' has no Harvest id", error_name, kind, name)
+            }
+        }
+    }
+}
+
+impl From<ureq::Error> for ApiError {
+    fn from(error: ureq::Error) -> Self {
+        match error {
+            ureq::Error::Status(status, response) => {
+                Self::Status(status, response.into_string().unwrap_or_default())
+            }
+            ureq::Error::Transport(transport) => Self::Transport(transport.to_string()),
+        }
+    }
+}
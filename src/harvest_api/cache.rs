@@ -0,0 +1,246 @@
+use crate::harvest_api::{api_error::ApiError, client::DEFAULT_BASE_URL};
+use crate::projects_and_tasks::harvest::projects::HarvestProjectAssignments;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum CacheMode {
+    UseFresh,
+    Refresh,
+    Offline,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Origin {
+    Api,
+    ApiWithoutCache(String),
+    Cache(DateTime<Utc>),
+    CacheAfterFailure(DateTime<Utc>, ApiError),
+}
+
+#[derive(Deserialize, Serialize, Debug, PartialEq)]
+struct CachedAssignments {
+    fetched_at: DateTime<Utc>,
+    assignments: HarvestProjectAssignments,
+}
+
+pub struct ProjectsCache {
+    file: PathBuf,
+    ttl: Duration,
+}
+
+impl ProjectsCache {
+    pub fn new(file: PathBuf, ttl: Duration) -> Self {
+        Self { file, ttl }
+    }
+
+    pub fn in_cache_directory(
+        account_id: &str,
+        base_url: Option<&str>,
+        ttl: Duration,
+    ) -> Option<Self> {
+        let file = dirs::cache_dir()?
+            .join("timing")
+            .join(file_name(account_id, base_url));
+        Some(Self::new(file, ttl))
+    }
+
+    pub fn file(&self) -> &Path {
+        &self.file
+    }
+
+    pub fn load<F>(
+        &self,
+        mode: CacheMode,
+        now: DateTime<Utc>,
+        fetch: F,
+    ) -> Result<(HarvestProjectAssignments, Origin), ApiError>
+    where
+        F: FnOnce() -> Result<HarvestProjectAssignments, ApiError>,
+    {
+        let cached = self.read();
+        match (mode, cached) {
+            (CacheMode::Offline, Some(cached)) => {
+                Ok((cached.assignments, Origin::Cache(cached.fetched_at)))
+            }
+            (CacheMode::Offline, None) => Err(ApiError::NotCached),
+            (CacheMode::UseFresh, Some(cached)) if now - cached.fetched_at < self.ttl => {
+                Ok((cached.assignments, Origin::Cache(cached.fetched_at)))
+            }
+            (_, cached) => match fetch() {
+                Ok(assignments) => {
+                    let cached = CachedAssignments {
+                        fetched_at: now,
+                        assignments,
+                    };
+                    match self.write(&cached) {
+                        Ok(()) => Ok((cached.assignments, Origin::Api)),
+                        Err(err) => {
+                            Ok((cached.assignments, Origin::ApiWithoutCache(err.to_string())))
+                        }
+                    }
+                }
+                Err(error) => match cached {
+                    Some(cached) => Ok((
+                        cached.assignments,
+                        Origin::CacheAfterFailure(cached.fetched_at, error),
+                    )),
+                    None => Err(error),
+                },
+            },
+        }
+    }
+
+    fn read(&self) -> Option<CachedAssignments> {
+        let content = std::fs::read_to_string(&self.file).ok()?;
+        serde_json::from_str(&content).ok()
+    }
+
+    fn write(&self, cached: &CachedAssignments) -> std::io::Result<()> {
+        if let Some(directory) = self.file.parent() {
+            std::fs::create_dir_all(directory)?;
+        }
+        std::fs::write(&self.file, serde_json::to_string(cached)?)
+    }
+}
+
+fn file_name(account_id: &str, base_url: Option<&str>) -> String {
+    let base_url = base_url.unwrap_or(DEFAULT_BASE_URL).trim_end_matches('/');
+    let hash = format!("{:x}", Sha256::digest(base_url.as_bytes()));
+    format!("project_assignments-{}-{}.json", account_id, &hash[..12])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn cache(name: &str) -> ProjectsCache {
+        let file = std::env::temp_dir()
+            .join(format!("timing-test-{}", std::process::id()))
+            .join(format!("{}.json", name));
+        if file.exists() {
+            std::fs::remove_file(&file).unwrap();
+        }
+        ProjectsCache::new(file, Duration::hours(24))
+    }
+
+    fn write(cache: &ProjectsCache, name: &str) {
+        cache
+            .write(&CachedAssignments {
+                fetched_at: fetched_at(),
+                assignments: assignments(name),
+            })
+            .unwrap();
+    }
+
+    fn assignments(name: &str) -> HarvestProjectAssignments {
+        HarvestProjectAssignments::from(&format!(
+            r#"{{"project_assignments": [{{"project": {{"id": 1, "name": "{}"}}, "task_assignments": []}}]}}"#,
+            name
+        ))
+        .unwrap()
+    }
+
+    fn fetched_at() -> DateTime<Utc> {
+        Utc.ymd(2021, 4, 1).and_hms(8, 0, 0)
+    }
+
+    #[test]
+    fn it_fetches_and_caches_when_nothing_is_cached() {
+        let cache = cache("fetch");
+
+        let loaded = cache.load(CacheMode::UseFresh, fetched_at(), || Ok(assignments("api")));
+
+        assert_eq!(loaded, Ok((assignments("api"), Origin::Api)));
+        assert_eq!(cache.read().unwrap().fetched_at, fetched_at());
+    }
+
+    #[test]
+    fn it_uses_a_fresh_cache_without_fetching() {
+        let cache = cache("fresh");
+        write(&cache, "cached");
+
+        let loaded = cache.load(
+            CacheMode::UseFresh,
+            fetched_at() + Duration::hours(1),
+            || panic!("must not fetch"),
+        );
+
+        assert_eq!(
+            loaded,
+            Ok((assignments("cached"), Origin::Cache(fetched_at())))
+        );
+    }
+
+    #[test]
+    fn it_fetches_when_the_cache_is_outdated_or_a_refresh_is_requested() {
+        let cache = cache("outdated");
+        write(&cache, "cached");
+
+        let outdated = cache.load(
+            CacheMode::UseFresh,
+            fetched_at() + Duration::hours(25),
+            || Ok(assignments("api")),
+        );
+        let refreshed = cache.load(CacheMode::Refresh, fetched_at(), || {
+            Ok(assignments("refreshed"))
+        });
+
+        assert_eq!(outdated, Ok((assignments("api"), Origin::Api)));
+        assert_eq!(refreshed, Ok((assignments("refreshed"), Origin::Api)));
+    }
+
+    #[test]
+    fn it_falls_back_to_the_cache_when_fetching_fails() {
+        let cache = cache("fallback");
+        write(&cache, "cached");
+        let error = ApiError::Transport("offline".to_string());
+
+        let loaded = cache.load(CacheMode::Refresh, fetched_at(), || Err(error.clone()));
+
+        assert_eq!(
+            loaded,
+            Ok((
+                assignments("cached"),
+                Origin::CacheAfterFailure(fetched_at(), error)
+            ))
+        );
+    }
+
+    #[test]
+    fn it_only_uses_the_cache_when_offline() {
+        let cache = cache("offline");
+
+        let loaded = cache.load(CacheMode::Offline, fetched_at(), || {
+            panic!("must not fetch")
+        });
+
+        assert_eq!(loaded, Err(ApiError::NotCached));
+    }
+
+    #[test]
+    fn it_reports_when_the_cache_cannot_be_written() {
+        let directory = cache("directory");
+        std::fs::create_dir_all(directory.file()).unwrap();
+
+        let loaded = directory.load(CacheMode::Refresh, fetched_at(), || Ok(assignments("api")));
+
+        assert!(matches!(loaded, Ok((_, Origin::ApiWithoutCache(_)))));
+    }
+
+    #[test]
+    fn it_caches_per_account_and_base_url() {
+        assert_eq!(
+            file_name("123", None),
+            file_name("123", Some("https://api.harvestapp.com/"))
+        );
+        assert_ne!(
+            file_name("123", None),
+            file_name("123", Some("http://localhost:8080"))
+        );
+        assert_ne!(file_name("123", None), file_name("456", None));
+    }
+}
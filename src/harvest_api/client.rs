@@ -0,0 +1,311 @@
+use crate::config::HarvestConfig;
+use crate::harvest_api::{
+    api_error::ApiError, remote_time_entry::RemoteTimeEntry, time_entry::TimeEntry,
+};
+use crate::projects_and_tasks::harvest::projects::HarvestProjectAssignments;
+use chrono::NaiveDate;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+
+pub const DEFAULT_BASE_URL: &str = "https://api.harvestapp.com";
+const USER_AGENT: &str = "timing (https://github.com/jvolmer/timing)";
+const PER_PAGE: &str = "100";
+
+pub struct Client {
+    base_url: String,
+    account_id: String,
+    token: String,
+    agent: ureq::Agent,
+}
+
+#[derive(Deserialize)]
+struct Page<T> {
+    #[serde(flatten)]
+    items: T,
+    next_page: Option<u32>,
+}
+
+#[derive(Deserialize)]
+struct TimeEntries {
+    time_entries: Vec<RemoteTimeEntry>,
+}
+
+#[derive(Deserialize)]
+struct User {
+    id: u64,
+}
+
+#[derive(Deserialize)]
+struct CreatedTimeEntry {
+    id: u64,
+}
+
+impl Client {
+    pub fn new(config: &HarvestConfig) -> Result<Self, ApiError> {
+        Ok(Self {
+            base_url: config
+                .base_url
+                .clone()
+                .unwrap_or_else(|| DEFAULT_BASE_URL.to_string()),
+            account_id: config
+                .account_id
+                .clone()
+                .ok_or(ApiError::MissingSetting("account_id"))?,
+            token: config
+                .token
+                .clone()
+                .ok_or(ApiError::MissingSetting("token"))?,
+            agent: ureq::AgentBuilder::new().build(),
+        })
+    }
+
+    pub fn project_assignments(&self) -> Result<HarvestProjectAssignments, ApiError> {
+        let mut assignments = HarvestProjectAssignments::default();
+        for page in self.get_pages("/v2/users/me/project_assignments", &[])? {
+            assignments.append(page);
+        }
+        Ok(assignments)
+    }
+
+    pub fn time_entries(
+        &self,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Result<Vec<RemoteTimeEntry>, ApiError> {
+        let user: User = self.get("/v2/users/me", &[])?;
+        let pages: Vec<TimeEntries> = self.get_pages(
+            "/v2/time_entries",
+            &[
+                ("user_id", &user.id.to_string()),
+                ("from", &from.format("%Y-%m-%d").to_string()),
+                ("to", &to.format("%Y-%m-%d").to_string()),
+            ],
+        )?;
+        Ok(pages
+            .into_iter()
+            .flat_map(|page| page.time_entries)
+            .collect())
+    }
+
+    pub fn create_time_entry(&self, entry: &TimeEntry) -> Result<u64, ApiError> {
+        let response = self.request("POST", "/v2/time_entries").send_json(entry)?;
+        response
+            .into_json::<CreatedTimeEntry>()
+            .map(|created| created.id)
+            .map_err(|error| ApiError::InvalidResponse(error.to_string()))
+    }
+
+    pub fn update_time_entry(&self, id: u64, entry: &TimeEntry) -> Result<(), ApiError> {
+        self.request("PATCH", &format!("/v2/time_entries/{}", id))
+            .send_json(entry)?;
+        Ok(())
+    }
+
+    pub fn delete_time_entry(&self, id: u64) -> Result<(), ApiError> {
+        self.request("DELETE", &format!("/v2/time_entries/{}", id))
+            .call()?;
+        Ok(())
+    }
+
+    fn get_pages<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        query: &[(&str, &str)],
+    ) -> Result<Vec<T>, ApiError> {
+        let mut items = vec![];
+        let mut page = Some(1);
+        while let Some(number) = page {
+            let number = number.to_string();
+            let mut page_query = query.to_vec();
+            page_query.extend(&[("page", number.as_str()), ("per_page", PER_PAGE)]);
+            let response: Page<T> = self.get(path, &page_query)?;
+            items.push(response.items);
+            page = response.next_page;
+        }
+        Ok(items)
+    }
+
+    fn get<T: DeserializeOwned>(&self, path: &str, query: &[(&str, &str)]) -> Result<T, ApiError> {
+        let response = self
+            .request("GET", path)
+            .query_pairs(query.iter().copied())
+            .call()?;
+        response
+            .into_json()
+            .map_err(|error| ApiError::InvalidResponse(error.to_string()))
+    }
+
+    fn request(&self, method: &str, path: &str) -> ureq::Request {
+        self.agent
+            .request(
+                method,
+                &format!("{}{}", self.base_url.trim_end_matches('/'), path),
+            )
+            .set("Authorization", &format!("Bearer {}", self.token))
+            .set("Harvest-Account-Id", &self.account_id)
+            .set("User-Agent", USER_AGENT)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::harvest_api::test_server::TestServer;
+    use crate::projects_and_tasks::{
+        project::ProjectWithTasksBuilder, projects::ProjectsBuilder, task::TaskBuilder,
+        tasks::TasksBuilder,
+    };
+
+    fn client(base_url: &str) -> Client {
+        Client::new(&HarvestConfig {
+            account_id: Some("4711".to_string()),
+            token: Some("secret".to_string()),
+            base_url: Some(base_url.to_string()),
+            cache_ttl_hours: None,
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn it_needs_account_id_and_token() {
+        let client = Client::new(&HarvestConfig {
+            account_id: Some("4711".to_string()),
+            token: None,
+            base_url: None,
+            cache_ttl_hours: None,
+        });
+
+        assert_eq!(client.err(), Some(ApiError::MissingSetting("token")));
+    }
+
+    #[test]
+    fn it_fetches_all_pages_of_project_assignments() {
+        let server = TestServer::start(vec![
+            (
+                200,
+                r#"{"project_assignments": [{"project": {"id": 1, "name": "A"},
+                    "task_assignments": [{"task": {"id": 10, "name": "T"}}]}],
+                    "next_page": 2}"#,
+            ),
+            (
+                200,
+                r#"{"project_assignments": [{"project": {"id": 2, "name": "B"},
+                    "task_assignments": []}], "next_page": null}"#,
+            ),
+        ]);
+
+        let assignments = client(server.url()).project_assignments().unwrap();
+        let requests = server.requests();
+
+        assert_eq!(
+            assignments.to_projects(),
+            ProjectsBuilder::new()
+                .with_projects(vec![
+                    ProjectWithTasksBuilder::new()
+                        .with_id(1)
+                        .with_name("A".to_string())
+                        .with_tasks(
+                            TasksBuilder::new()
+                                .with_tasks(vec![TaskBuilder::new()
+                                    .with_id(10)
+                                    .with_name("T".to_string())
+                                    .build()])
+                                .build()
+                        )
+                        .build(),
+                    ProjectWithTasksBuilder::new()
+                        .with_id(2)
+                        .with_name("B".to_string())
+                        .build()
+                ])
+                .build()
+        );
+        assert_eq!(
+            requests
+                .iter()
+                .map(|request| request.url.as_str())
+                .collect::<Vec<_>>(),
+            vec![
+                "/v2/users/me/project_assignments?page=1&per_page=100",
+                "/v2/users/me/project_assignments?page=2&per_page=100"
+            ]
+        );
+        assert_eq!(requests[0].header("Authorization"), Some("Bearer secret"));
+        assert_eq!(requests[0].header("Harvest-Account-Id"), Some("4711"));
+    }
+
+    #[test]
+    fn it_creates_time_entries() {
+        let server = TestServer::start(vec![(201, r#"{"id": 636709355, "hours": 0.5}"#)]);
+        let entry = TimeEntry {
+            project_id: 1,
+            task_id: 10,
+            spent_date: "2020-01-12".to_string(),
+            started_time: "8:00am".to_string(),
+            ended_time: "8:30am".to_string(),
+            hours: 0.5,
+            notes: "Improve Readme".to_string(),
+        };
+
+        let id = client(server.url()).create_time_entry(&entry);
+        let requests = server.requests();
+
+        assert_eq!(id, Ok(636709355));
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].url, "/v2/time_entries");
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&requests[0].body).unwrap(),
+            serde_json::json!({
+                "project_id": 1,
+                "task_id": 10,
+                "spent_date": "2020-01-12",
+                "started_time": "8:00am",
+                "ended_time": "8:30am",
+                "hours": 0.5,
+                "notes": "Improve Readme"
+            })
+        );
+    }
+
+    #[test]
+    fn it_fetches_time_entries_of_the_current_user() {
+        let server = TestServer::start(vec![
+            (200, r#"{"id": 1782959}"#),
+            (
+                200,
+                r#"{"time_entries": [{"id": 636709355, "spent_date": "2020-01-12",
+                    "started_time": "8:00am", "ended_time": "8:30am", "hours": 0.5,
+                    "notes": "Improve Readme", "project": {"id": 1, "name": "A"},
+                    "task": {"id": 10, "name": "T"}}], "next_page": null}"#,
+            ),
+        ]);
+
+        let entries = client(server.url()).time_entries(
+            NaiveDate::from_ymd(2020, 1, 6),
+            NaiveDate::from_ymd(2020, 1, 12),
+        );
+        let requests = server.requests();
+
+        assert_eq!(entries.map(|entries| entries.len()), Ok(1));
+        assert_eq!(
+            requests[1].url,
+            "/v2/time_entries?user_id=1782959&from=2020-01-06&to=2020-01-12&page=1&per_page=100"
+        );
+    }
+
+    #[test]
+    fn it_reports_failed_requests() {
+        let server = TestServer::start(vec![(401, r#"{"error": "invalid_token"}"#)]);
+
+        let assignments = client(server.url()).project_assignments();
+        server.requests();
+
+        assert_eq!(
+            assignments,
+            Err(ApiError::Status(
+                401,
+                r#"{"error": "invalid_token"}"#.to_string()
+            ))
+        );
+    }
+}
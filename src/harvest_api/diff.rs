@@ -0,0 +1,155 @@
+use crate::validation::activity::Activity;
+use chrono::Duration;
+use std::fmt;
+
+#[derive(Debug, PartialEq)]
+pub enum Difference {
+    OnlyLocal(Activity),
+    OnlyRemote(u64, Activity),
+    Changed(Activity, u64, Activity),
+}
+
+impl fmt::Display for Difference {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self {
+            Self::OnlyLocal(activity) => write!(f, "{:<20} {}", "only local", activity),
+            Self::OnlyRemote(id, activity) => {
+                write!(f, "{:<20} {}", format!("only remote {}", id), activity)
+            }
+            Self::Changed(local, id, remote) => write!(
+                f,
+                "{:<20} {}\n{:<20} {}",
+                "changed local",
+                local,
+                format!("changed remote {}", id),
+                remote
+            ),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct RemoteActivity {
+    pub id: u64,
+    pub activity: Activity,
+    pub duration_only: bool,
+}
+
+impl RemoteActivity {
+    fn matches(&self, activity: &Activity) -> bool {
+        if !self.duration_only {
+            return self.activity.start() == activity.start();
+        }
+        self.activity.start().date().date() == activity.start().date().date()
+            && self.activity.project() == activity.project()
+            && self.activity.task() == activity.task()
+            && duration(&self.activity) == duration(activity)
+    }
+
+    fn is_same_as(&self, activity: &Activity) -> bool {
+        match self.duration_only {
+            true => self.activity.description() == activity.description(),
+            false => &self.activity == activity,
+        }
+    }
+}
+
+fn duration(activity: &Activity) -> Duration {
+    *activity.end().date() - *activity.start().date()
+}
+
+pub fn diff(local: Vec<Activity>, remote: Vec<RemoteActivity>) -> Vec<Difference> {
+    let mut remote = remote;
+    let mut differences = vec![];
+
+    for activity in local {
+        let position = remote.iter().position(|remote| remote.matches(&activity));
+        match position.map(|position| remote.remove(position)) {
+            Some(remote) if remote.is_same_as(&activity) => {}
+            Some(remote) => {
+                differences.push(Difference::Changed(activity, remote.id, remote.activity))
+            }
+            None => differences.push(Difference::OnlyLocal(activity)),
+        }
+    }
+    differences.extend(
+        remote
+            .into_iter()
+            .map(|remote| Difference::OnlyRemote(remote.id, remote.activity)),
+    );
+    differences
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::{time, ActivityBuilder};
+
+    fn activity(hour: u32, description: &str) -> Activity {
+        ActivityBuilder::new()
+            .with_times(time(1, 12, hour, 0), time(1, 12, hour, 30))
+            .with_description(description)
+            .build()
+    }
+
+    fn remote(id: u64, activity: Activity) -> RemoteActivity {
+        RemoteActivity {
+            id,
+            activity,
+            duration_only: false,
+        }
+    }
+
+    fn duration_only(id: u64, description: &str) -> RemoteActivity {
+        RemoteActivity {
+            id,
+            activity: ActivityBuilder::new()
+                .with_times(time(1, 12, 0, 0), time(1, 12, 0, 30))
+                .with_description(description)
+                .build(),
+            duration_only: true,
+        }
+    }
+
+    #[test]
+    fn it_finds_local_remote_and_changed_activities() {
+        let local = vec![
+            activity(8, "same"),
+            activity(9, "local"),
+            activity(10, "only local"),
+        ];
+        let remote = vec![
+            remote(1, activity(8, "same")),
+            remote(2, activity(9, "remote")),
+            remote(3, activity(11, "only remote")),
+        ];
+
+        let differences = diff(local, remote);
+
+        assert_eq!(
+            differences,
+            vec![
+                Difference::Changed(activity(9, "local"), 2, activity(9, "remote")),
+                Difference::OnlyLocal(activity(10, "only local")),
+                Difference::OnlyRemote(3, activity(11, "only remote")),
+            ]
+        );
+    }
+
+    #[test]
+    fn it_matches_duration_only_entries_by_date_project_task_and_duration() {
+        let local = vec![activity(8, "same"), activity(9, "local")];
+        let remote = vec![duration_only(1, "same"), duration_only(2, "remote")];
+
+        let differences = diff(local, remote);
+
+        assert_eq!(
+            differences,
+            vec![Difference::Changed(
+                activity(9, "local"),
+                2,
+                duration_only(2, "remote").activity
+            )]
+        );
+    }
+}
@@ -0,0 +1,113 @@
+use crate::validation::activity::Activity;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+#[derive(Deserialize, Serialize, Debug, PartialEq, Clone)]
+pub struct LedgerEntry {
+    pub fingerprint: String,
+    pub start: String,
+    pub remote_id: u64,
+}
+
+#[derive(Deserialize, Serialize, Debug, PartialEq, Default)]
+pub struct Ledger {
+    entries: Vec<LedgerEntry>,
+}
+
+impl Ledger {
+    pub fn from(string: &str) -> serde_json::Result<Ledger> {
+        serde_json::from_str(string)
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap()
+    }
+
+    pub fn entries(&self) -> &[LedgerEntry] {
+        &self.entries
+    }
+
+    pub fn record(&mut self, entry: LedgerEntry) {
+        self.remove(entry.remote_id);
+        self.entries.push(entry);
+    }
+
+    pub fn remove(&mut self, remote_id: u64) {
+        self.entries.retain(|entry| entry.remote_id != remote_id);
+    }
+}
+
+pub fn fingerprint(activity: &Activity) -> String {
+    let fields = [
+        activity.start().date().to_rfc3339(),
+        activity.end().date().to_rfc3339(),
+        optional_id(activity.project().id()),
+        optional_id(activity.task().id()),
+        activity.description().text().to_string(),
+    ];
+    let mut hasher = Sha256::new();
+    for field in &fields {
+        hasher.update(field.as_bytes());
+        hasher.update([0]);
+    }
+    format!("{:x}", hasher.finalize())
+}
+
+fn optional_id(id: Option<u32>) -> String {
+    id.map(|id| id.to_string()).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::projects_and_tasks::{project::ProjectBuilder, task::TaskBuilder};
+    use crate::test_fixtures::ActivityBuilder;
+
+    fn activity(description: &str) -> Activity {
+        ActivityBuilder::new()
+            .with_project(ProjectBuilder::new().with_id(3).build())
+            .with_task(TaskBuilder::new().with_id(7).build())
+            .with_description(description)
+            .build()
+    }
+
+    #[test]
+    fn it_gives_same_fingerprint_for_same_activity() {
+        assert_eq!(
+            fingerprint(&activity("Improve Readme")),
+            fingerprint(&activity("Improve Readme"))
+        );
+    }
+
+    #[test]
+    fn it_gives_different_fingerprints_for_changed_activities() {
+        assert_ne!(
+            fingerprint(&activity("Improve Readme")),
+            fingerprint(&activity("Improve Readme file"))
+        );
+    }
+
+    #[test]
+    fn it_replaces_entries_with_same_remote_id() {
+        let mut ledger = Ledger::default();
+        ledger.record(LedgerEntry {
+            fingerprint: "a".to_string(),
+            start: "s".to_string(),
+            remote_id: 1,
+        });
+        ledger.record(LedgerEntry {
+            fingerprint: "b".to_string(),
+            start: "s".to_string(),
+            remote_id: 1,
+        });
+
+        assert_eq!(
+            Ledger::from(&ledger.to_json()).unwrap().entries(),
+            &[LedgerEntry {
+                fingerprint: "b".to_string(),
+                start: "s".to_string(),
+                remote_id: 1,
+            }]
+        );
+    }
+}
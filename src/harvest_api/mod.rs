@@ -0,0 +1,10 @@
+pub mod api_error;
+pub mod cache;
+pub mod client;
+pub mod diff;
+pub mod ledger;
+pub mod remote_time_entry;
+pub mod sync;
+#[cfg(test)]
+mod test_server;
+pub mod time_entry;
@@ -0,0 +1,157 @@
+use crate::harvest_api::api_error::ApiError;
+use crate::projects_and_tasks::{
+    project::{Project, ProjectWithTasksBuilder},
+    projects::Projects,
+    task::{Task, TaskBuilder},
+};
+use crate::validation::{
+    activity::{Activity, Description},
+    time::{End, Start},
+};
+use chrono::prelude::*;
+use chrono::Duration;
+use serde::Deserialize;
+
+#[derive(Deserialize, Debug, PartialEq)]
+pub struct RemoteTimeEntry {
+    pub id: u64,
+    pub spent_date: String,
+    pub started_time: Option<String>,
+    pub ended_time: Option<String>,
+    pub hours: f64,
+    pub notes: Option<String>,
+    pub project: RemoteIdentification,
+    pub task: RemoteIdentification,
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+pub struct RemoteIdentification {
+    pub id: u32,
+    pub name: String,
+}
+
+impl RemoteTimeEntry {
+    pub fn to_activity(&self, projects: &Projects) -> Result<Activity, ApiError> {
+        let date = NaiveDate::parse_from_str(&self.spent_date, "%Y-%m-%d")
+            .map_err(|_| self.invalid("spent_date"))?;
+        let start = match &self.started_time {
+            Some(time) => date.and_time(self.time(time, "started_time")?),
+            None => date.and_hms(0, 0, 0),
+        };
+        let end = match &self.ended_time {
+            Some(time) => date.and_time(self.time(time, "ended_time")?),
+            None => start + Duration::minutes((self.hours * 60.0).round() as i64),
+        };
+        let (project, task) = projects
+            .get_project_with_task_by_ids(self.project.id, self.task.id)
+            .unwrap_or_else(|_| self.project_and_task());
+
+        Ok(Activity::from(
+            Start::new(self.local(&start, "started_time")?),
+            End::new(self.local(&end, "ended_time")?),
+            project,
+            task,
+            Description::new(self.notes.clone().unwrap_or_default()),
+        ))
+    }
+
+    pub fn has_times(&self) -> bool {
+        self.started_time.is_some()
+    }
+
+    fn local(&self, date_time: &NaiveDateTime, field: &str) -> Result<DateTime<Local>, ApiError> {
+        Local
+            .from_local_datetime(date_time)
+            .earliest()
+            .ok_or_else(|| self.invalid(field))
+    }
+
+    fn time(&self, time: &str, field: &str) -> Result<NaiveTime, ApiError> {
+        NaiveTime::parse_from_str(time, "%I:%M%p")
+            .or_else(|_| NaiveTime::parse_from_str(time, "%H:%M"))
+            .map_err(|_| self.invalid(field))
+    }
+
+    fn project_and_task(&self) -> (Project, Task) {
+        let project = ProjectWithTasksBuilder::new()
+            .with_id(self.project.id)
+            .with_name(self.project.name.clone())
+            .build();
+        let task = TaskBuilder::new()
+            .with_id(self.task.id)
+            .with_name(self.task.name.clone())
+            .build();
+        (Project::new(&project), task)
+    }
+
+    fn invalid(&self, field: &str) -> ApiError {
+        ApiError::InvalidResponse(format!("Time entry {} has an invalid {}", self.id, field))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::projects_and_tasks::{projects::ProjectsBuilder, tasks::TasksBuilder};
+
+    fn remote_entry(started_time: Option<&str>, ended_time: Option<&str>) -> RemoteTimeEntry {
+        RemoteTimeEntry {
+            id: 636709355,
+            spent_date: "2020-01-12".to_string(),
+            started_time: started_time.map(str::to_string),
+            ended_time: ended_time.map(str::to_string),
+            hours: 1.5,
+            notes: Some("Improve Readme".to_string()),
+            project: RemoteIdentification {
+                id: 1,
+                name: "Remote project".to_string(),
+            },
+            task: RemoteIdentification {
+                id: 2,
+                name: "Remote task".to_string(),
+            },
+        }
+    }
+
+    #[test]
+    fn it_maps_ids_to_local_projects_and_tasks() {
+        let task = TaskBuilder::new()
+            .with_id(2)
+            .with_name("Build".to_string())
+            .build();
+        let project = ProjectWithTasksBuilder::new()
+            .with_id(1)
+            .with_name("Timeval".to_string())
+            .with_tasks(TasksBuilder::new().with_tasks(vec![task.clone()]).build())
+            .build();
+        let projects = ProjectsBuilder::new()
+            .with_projects(vec![project.clone()])
+            .build();
+
+        let activity = remote_entry(Some("8:00am"), Some("1:30pm")).to_activity(&projects);
+
+        assert_eq!(
+            activity,
+            Ok(Activity::from(
+                Start::new(Local.ymd(2020, 1, 12).and_hms(8, 0, 0)),
+                End::new(Local.ymd(2020, 1, 12).and_hms(13, 30, 0)),
+                Project::new(&project),
+                task,
+                Description::new("Improve Readme".to_string())
+            ))
+        );
+    }
+
+    #[test]
+    fn it_uses_remote_names_and_hours_when_not_known_locally() {
+        let projects = ProjectsBuilder::new().build();
+
+        let activity = remote_entry(None, None).to_activity(&projects).unwrap();
+
+        assert_eq!(activity.project().name(), "Remote project");
+        assert_eq!(
+            activity.end().date(),
+            &Local.ymd(2020, 1, 12).and_hms(1, 30, 0)
+        );
+    }
+}
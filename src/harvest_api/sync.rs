@@ -0,0 +1,325 @@
+use crate::harvest_api::{
+    api_error::ApiError,
+    client::Client,
+    ledger::{fingerprint, Ledger, LedgerEntry},
+    time_entry::TimeEntry,
+};
+use crate::validation::activity::Activity;
+use std::fmt;
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct LocalEntry {
+    pub fingerprint: String,
+    pub start: String,
+    pub time_entry: TimeEntry,
+}
+
+impl LocalEntry {
+    pub fn from(activity: &Activity) -> Result<Self, ApiError> {
+        Ok(Self {
+            fingerprint: fingerprint(activity),
+            start: activity.start().date().to_rfc3339(),
+            time_entry: TimeEntry::from(activity)?,
+        })
+    }
+
+    fn recorded_as(&self, remote_id: u64) -> LedgerEntry {
+        LedgerEntry {
+            fingerprint: self.fingerprint.clone(),
+            start: self.start.clone(),
+            remote_id,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum SyncAction {
+    Create(LocalEntry),
+    Update(u64, LocalEntry),
+    Delete(LedgerEntry),
+    Keep(LedgerEntry),
+    Unchanged(LedgerEntry),
+}
+
+impl fmt::Display for SyncAction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self {
+            Self::Create(local) => write!(
+                f,
+                "create    {}",
+                serde_json::to_string(&local.time_entry).unwrap()
+            ),
+            Self::Update(remote_id, local) => write!(
+                f,
+                "update    {} {}",
+                remote_id,
+                serde_json::to_string(&local.time_entry).unwrap()
+            ),
+            Self::Delete(recorded) => write!(f, "delete    {}", recorded.remote_id),
+            Self::Keep(recorded) => write!(f, "keep      {}", recorded.remote_id),
+            Self::Unchanged(recorded) => write!(f, "unchanged {}", recorded.remote_id),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum SyncResult {
+    Created(u64, TimeEntry),
+    Updated(u64, TimeEntry),
+    Deleted(u64),
+    Kept(u64),
+    Unchanged(u64),
+}
+
+impl fmt::Display for SyncResult {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self {
+            Self::Created(id, entry) => write!(
+                f,
+                "Created time entry {} on {} from {} to {}",
+                id, entry.spent_date, entry.started_time, entry.ended_time
+            ),
+            Self::Updated(id, entry) => write!(
+                f,
+                "Updated time entry {} on {} from {} to {}",
+                id, entry.spent_date, entry.started_time, entry.ended_time
+            ),
+            Self::Deleted(id) => write!(f, "Deleted time entry {}", id),
+            Self::Kept(id) => write!(
+                f,
+                "Kept time entry {} which is not in the timesheet anymore, use --delete to delete it",
+                id
+            ),
+            Self::Unchanged(id) => write!(f, "Time entry {} is unchanged", id),
+        }
+    }
+}
+
+pub fn plan(entries: Vec<LocalEntry>, ledger: &Ledger, delete: bool) -> Vec<SyncAction> {
+    let mut unmatched = ledger.entries().to_vec();
+    let mut actions = vec![];
+    let mut changed = vec![];
+
+    for local in entries {
+        match take(&mut unmatched, |recorded| {
+            recorded.fingerprint == local.fingerprint
+        }) {
+            Some(recorded) => actions.push(SyncAction::Unchanged(recorded)),
+            None => changed.push(local),
+        }
+    }
+    for local in changed {
+        match take(&mut unmatched, |recorded| recorded.start == local.start) {
+            Some(recorded) => actions.push(SyncAction::Update(recorded.remote_id, local)),
+            None => actions.push(SyncAction::Create(local)),
+        }
+    }
+    actions.extend(unmatched.into_iter().map(|recorded| match delete {
+        true => SyncAction::Delete(recorded),
+        false => SyncAction::Keep(recorded),
+    }));
+    actions
+}
+
+fn take<F>(entries: &mut Vec<LedgerEntry>, predicate: F) -> Option<LedgerEntry>
+where
+    F: Fn(&LedgerEntry) -> bool,
+{
+    let position = entries.iter().position(predicate)?;
+    Some(entries.remove(position))
+}
+
+pub fn execute(
+    client: &Client,
+    actions: Vec<SyncAction>,
+    ledger: &mut Ledger,
+) -> Result<Vec<SyncResult>, ApiError> {
+    let mut results = vec![];
+    for action in actions {
+        let result = match action {
+            SyncAction::Create(local) => create(client, local, ledger)?,
+            SyncAction::Update(remote_id, local) => {
+                match client.update_time_entry(remote_id, &local.time_entry) {
+                    Ok(()) => {
+                        ledger.record(local.recorded_as(remote_id));
+                        SyncResult::Updated(remote_id, local.time_entry)
+                    }
+                    Err(ApiError::Status(404, _)) => {
+                        ledger.remove(remote_id);
+                        create(client, local, ledger)?
+                    }
+                    Err(error) => return Err(error),
+                }
+            }
+            SyncAction::Delete(recorded) => {
+                match client.delete_time_entry(recorded.remote_id) {
+                    Ok(()) | Err(ApiError::Status(404, _)) => {}
+                    Err(error) => return Err(error),
+                }
+                ledger.remove(recorded.remote_id);
+                SyncResult::Deleted(recorded.remote_id)
+            }
+            SyncAction::Keep(recorded) => SyncResult::Kept(recorded.remote_id),
+            SyncAction::Unchanged(recorded) => SyncResult::Unchanged(recorded.remote_id),
+        };
+        results.push(result);
+    }
+    Ok(results)
+}
+
+fn create(client: &Client, local: LocalEntry, ledger: &mut Ledger) -> Result<SyncResult, ApiError> {
+    let remote_id = client.create_time_entry(&local.time_entry)?;
+    ledger.record(local.recorded_as(remote_id));
+    Ok(SyncResult::Created(remote_id, local.time_entry))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::HarvestConfig;
+    use crate::harvest_api::test_server::TestServer;
+
+    fn local(fingerprint: &str, start: &str) -> LocalEntry {
+        LocalEntry {
+            fingerprint: fingerprint.to_string(),
+            start: start.to_string(),
+            time_entry: TimeEntry {
+                project_id: 1,
+                task_id: 2,
+                spent_date: "2020-01-12".to_string(),
+                started_time: "8:00am".to_string(),
+                ended_time: "8:30am".to_string(),
+                hours: 0.5,
+                notes: fingerprint.to_string(),
+            },
+        }
+    }
+
+    fn recorded(fingerprint: &str, start: &str, remote_id: u64) -> LedgerEntry {
+        LedgerEntry {
+            fingerprint: fingerprint.to_string(),
+            start: start.to_string(),
+            remote_id,
+        }
+    }
+
+    fn ledger(entries: Vec<LedgerEntry>) -> Ledger {
+        let mut ledger = Ledger::default();
+        for entry in entries {
+            ledger.record(entry);
+        }
+        ledger
+    }
+
+    #[test]
+    fn it_plans_creates_updates_and_deletes() {
+        let ledger = ledger(vec![
+            recorded("unchanged", "08:00", 1),
+            recorded("old", "09:00", 2),
+            recorded("removed", "10:00", 3),
+        ]);
+        let entries = vec![
+            local("unchanged", "08:00"),
+            local("changed", "09:00"),
+            local("new", "11:00"),
+        ];
+
+        let actions = plan(entries, &ledger, true);
+
+        assert_eq!(
+            actions,
+            vec![
+                SyncAction::Unchanged(recorded("unchanged", "08:00", 1)),
+                SyncAction::Update(2, local("changed", "09:00")),
+                SyncAction::Create(local("new", "11:00")),
+                SyncAction::Delete(recorded("removed", "10:00", 3)),
+            ]
+        );
+    }
+
+    #[test]
+    fn it_executes_the_plan_and_records_remote_ids() {
+        let server = TestServer::start(vec![
+            (200, r#"{"id": 2}"#),
+            (201, r#"{"id": 4}"#),
+            (200, r#"{}"#),
+        ]);
+        let client = Client::new(&HarvestConfig {
+            account_id: Some("4711".to_string()),
+            token: Some("secret".to_string()),
+            base_url: Some(server.url().to_string()),
+            cache_ttl_hours: None,
+        })
+        .unwrap();
+        let mut ledger = ledger(vec![
+            recorded("old", "09:00", 2),
+            recorded("removed", "10:00", 3),
+        ]);
+        let actions = vec![
+            SyncAction::Update(2, local("changed", "09:00")),
+            SyncAction::Create(local("new", "11:00")),
+            SyncAction::Delete(recorded("removed", "10:00", 3)),
+        ];
+
+        let results = execute(&client, actions, &mut ledger).unwrap();
+        let requests = server.requests();
+
+        assert_eq!(
+            results,
+            vec![
+                SyncResult::Updated(2, local("changed", "09:00").time_entry),
+                SyncResult::Created(4, local("new", "11:00").time_entry),
+                SyncResult::Deleted(3),
+            ]
+        );
+        assert_eq!(
+            requests
+                .iter()
+                .map(|request| format!("{} {}", request.method, request.url))
+                .collect::<Vec<_>>(),
+            vec![
+                "PATCH /v2/time_entries/2",
+                "POST /v2/time_entries",
+                "DELETE /v2/time_entries/3"
+            ]
+        );
+        assert_eq!(
+            ledger.entries(),
+            &[recorded("changed", "09:00", 2), recorded("new", "11:00", 4)]
+        );
+    }
+
+    #[test]
+    fn it_plans_to_keep_removed_entries_without_delete() {
+        let ledger = ledger(vec![recorded("removed", "10:00", 3)]);
+
+        let actions = plan(vec![], &ledger, false);
+
+        assert_eq!(
+            actions,
+            vec![SyncAction::Keep(recorded("removed", "10:00", 3))]
+        );
+        assert_eq!(actions[0].to_string(), "keep      3");
+    }
+
+    #[test]
+    fn it_keeps_removed_entries_without_delete() {
+        let mut ledger = ledger(vec![recorded("removed", "10:00", 3)]);
+        let client = Client::new(&HarvestConfig {
+            account_id: Some("4711".to_string()),
+            token: Some("secret".to_string()),
+            base_url: Some("http://127.0.0.1:9".to_string()),
+            cache_ttl_hours: None,
+        })
+        .unwrap();
+
+        let results = execute(
+            &client,
+            vec![SyncAction::Keep(recorded("removed", "10:00", 3))],
+            &mut ledger,
+        );
+
+        assert_eq!(results, Ok(vec![SyncResult::Kept(3)]));
+        assert_eq!(ledger.entries(), &[recorded("removed", "10:00", 3)]);
+    }
+}
@@ -0,0 +1,86 @@
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+use tiny_http::{Header, Response, Server};
+
+#[derive(Debug, PartialEq)]
+pub struct RecordedRequest {
+    pub method: String,
+    pub url: String,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl RecordedRequest {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+pub struct TestServer {
+    url: String,
+    handle: JoinHandle<Vec<RecordedRequest>>,
+}
+
+impl TestServer {
+    pub fn start(responses: Vec<(u16, &str)>) -> Self {
+        let server = Server::http("127.0.0.1:0").unwrap();
+        let url = format!(
+            "http://127.0.0.1:{}",
+            server.server_addr().to_ip().unwrap().port()
+        );
+        let responses = responses
+            .into_iter()
+            .map(|(status, body)| (status, body.to_string()))
+            .collect::<Vec<_>>();
+
+        let handle = thread::spawn(move || {
+            responses
+                .into_iter()
+                .map(|(status, body)| {
+                    let mut request = server
+                        .recv_timeout(Duration::from_secs(5))
+                        .unwrap()
+                        .expect("Expected another request");
+                    let mut request_body = String::new();
+                    request
+                        .as_reader()
+                        .read_to_string(&mut request_body)
+                        .unwrap();
+                    let recorded = RecordedRequest {
+                        method: request.method().to_string(),
+                        url: request.url().to_string(),
+                        headers: request
+                            .headers()
+                            .iter()
+                            .map(|header| (header.field.to_string(), header.value.to_string()))
+                            .collect(),
+                        body: request_body,
+                    };
+                    let content_type =
+                        Header::from_bytes("Content-Type", "application/json").unwrap();
+                    request
+                        .respond(
+                            Response::from_string(body)
+                                .with_status_code(status)
+                                .with_header(content_type),
+                        )
+                        .unwrap();
+                    recorded
+                })
+                .collect()
+        });
+
+        Self { url, handle }
+    }
+
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    pub fn requests(self) -> Vec<RecordedRequest> {
+        self.handle.join().unwrap()
+    }
+}
@@ -0,0 +1,91 @@
+use crate::harvest_api::api_error::ApiError;
+use crate::projects_and_tasks::named::Named;
+use crate::validation::activity::Activity;
+use serde::Serialize;
+
+#[derive(Serialize, Debug, PartialEq, Clone)]
+pub struct TimeEntry {
+    pub project_id: u32,
+    pub task_id: u32,
+    pub spent_date: String,
+    pub started_time: String,
+    pub ended_time: String,
+    pub hours: f64,
+    pub notes: String,
+}
+
+impl TimeEntry {
+    pub fn from(activity: &Activity) -> Result<Self, ApiError> {
+        let project = activity.project();
+        let task = activity.task();
+        let start = activity.start().date();
+        let end = activity.end().date();
+        let minutes = (*end - *start).num_minutes();
+
+        Ok(Self {
+            project_id: project
+                .id()
+                .ok_or_else(|| ApiError::MissingId("Project", project.name().to_string()))?,
+            task_id: task
+                .id()
+                .ok_or_else(|| ApiError::MissingId("Task", task.name().to_string()))?,
+            spent_date: start.format("%Y-%m-%d").to_string(),
+            started_time: start.format("%-I:%M%P").to_string(),
+            ended_time: end.format("%-I:%M%P").to_string(),
+            hours: (minutes as f64 / 60.0 * 100.0).round() / 100.0,
+            notes: activity.description().text().to_string(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::projects_and_tasks::{
+        project::{Project, ProjectWithTasksBuilder},
+        task::TaskBuilder,
+    };
+    use crate::test_fixtures::{time, ActivityBuilder};
+
+    fn activity(project_id: Option<u32>) -> Activity {
+        ActivityBuilder::new()
+            .with_times(time(1, 12, 8, 0), time(1, 12, 13, 20))
+            .with_project(Project::new(
+                &ProjectWithTasksBuilder::new()
+                    .with_optional_id(project_id)
+                    .with_name("Timeval".to_string())
+                    .build(),
+            ))
+            .with_task(TaskBuilder::new().with_id(7).build())
+            .with_description("Improve Readme")
+            .build()
+    }
+
+    #[test]
+    fn it_creates_time_entry_from_activity() {
+        let entry = TimeEntry::from(&activity(Some(3)));
+
+        assert_eq!(
+            entry,
+            Ok(TimeEntry {
+                project_id: 3,
+                task_id: 7,
+                spent_date: "2020-01-12".to_string(),
+                started_time: "8:00am".to_string(),
+                ended_time: "1:20pm".to_string(),
+                hours: 5.33,
+                notes: "Improve Readme".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn it_needs_ids_of_project_and_task() {
+        let entry = TimeEntry::from(&activity(None));
+
+        assert_eq!(
+            entry,
+            Err(ApiError::MissingId("Project", "Timeval".to_string()))
+        );
+    }
+}
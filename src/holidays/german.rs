@@ -0,0 +1,173 @@
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+use std::str::FromStr;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum State {
+    BadenWuerttemberg,
+    Bayern,
+    Berlin,
+    Brandenburg,
+    Bremen,
+    Hamburg,
+    Hessen,
+    MecklenburgVorpommern,
+    Niedersachsen,
+    NordrheinWestfalen,
+    RheinlandPfalz,
+    Saarland,
+    Sachsen,
+    SachsenAnhalt,
+    SchleswigHolstein,
+    Thueringen,
+}
+
+impl FromStr for State {
+    type Err = String;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        match string.to_uppercase().as_str() {
+            "BW" => Ok(Self::BadenWuerttemberg),
+            "BY" => Ok(Self::Bayern),
+            "BE" => Ok(Self::Berlin),
+            "BB" => Ok(Self::Brandenburg),
+            "HB" => Ok(Self::Bremen),
+            "HH" => Ok(Self::Hamburg),
+            "HE" => Ok(Self::Hessen),
+            "MV" => Ok(Self::MecklenburgVorpommern),
+            "NI" => Ok(Self::Niedersachsen),
+            "NW" => Ok(Self::NordrheinWestfalen),
+            "RP" => Ok(Self::RheinlandPfalz),
+            "SL" => Ok(Self::Saarland),
+            "SN" => Ok(Self::Sachsen),
+            "ST" => Ok(Self::SachsenAnhalt),
+            "SH" => Ok(Self::SchleswigHolstein),
+            "TH" => Ok(Self::Thueringen),
+            _ => Err(format!(
+                "Unknown German state '{}', use a code like BY or NW",
+                string
+            )),
+        }
+    }
+}
+
+pub fn holidays(state: State, year: i32) -> Vec<(NaiveDate, &'static str)> {
+    use State::*;
+    let date = |month, day| NaiveDate::from_ymd(year, month, day);
+    let easter = easter(year);
+    let after_easter = |days| easter + Duration::days(days);
+    let is = |states: &[State]| states.contains(&state);
+
+    let mut holidays = vec![
+        (date(1, 1), "Neujahr"),
+        (after_easter(-2), "Karfreitag"),
+        (after_easter(1), "Ostermontag"),
+        (date(5, 1), "Tag der Arbeit"),
+        (after_easter(39), "Christi Himmelfahrt"),
+        (after_easter(50), "Pfingstmontag"),
+        (date(10, 3), "Tag der Deutschen Einheit"),
+        (date(12, 25), "1. Weihnachtstag"),
+        (date(12, 26), "2. Weihnachtstag"),
+    ];
+    if is(&[BadenWuerttemberg, Bayern, SachsenAnhalt]) {
+        holidays.push((date(1, 6), "Heilige Drei Könige"));
+    }
+    if (state == Berlin && year >= 2019) || (state == MecklenburgVorpommern && year >= 2023) {
+        holidays.push((date(3, 8), "Internationaler Frauentag"));
+    }
+    if state == Brandenburg {
+        holidays.push((easter, "Ostersonntag"));
+        holidays.push((after_easter(49), "Pfingstsonntag"));
+    }
+    if is(&[
+        BadenWuerttemberg,
+        Bayern,
+        Hessen,
+        NordrheinWestfalen,
+        RheinlandPfalz,
+        Saarland,
+    ]) {
+        holidays.push((after_easter(60), "Fronleichnam"));
+    }
+    if state == Saarland {
+        holidays.push((date(8, 15), "Mariä Himmelfahrt"));
+    }
+    if state == Thueringen && year >= 2019 {
+        holidays.push((date(9, 20), "Weltkindertag"));
+    }
+    if year == 2017
+        || is(&[
+            Brandenburg,
+            MecklenburgVorpommern,
+            Sachsen,
+            SachsenAnhalt,
+            Thueringen,
+        ])
+        || (year >= 2018 && is(&[Bremen, Hamburg, Niedersachsen, SchleswigHolstein]))
+    {
+        holidays.push((date(10, 31), "Reformationstag"));
+    }
+    if is(&[
+        BadenWuerttemberg,
+        Bayern,
+        NordrheinWestfalen,
+        RheinlandPfalz,
+        Saarland,
+    ]) {
+        holidays.push((date(11, 1), "Allerheiligen"));
+    }
+    if state == Sachsen {
+        let mut day = date(11, 22);
+        while day.weekday() != Weekday::Wed {
+            day = day.pred();
+        }
+        holidays.push((day, "Buß- und Bettag"));
+    }
+    holidays.sort();
+    holidays
+}
+
+fn easter(year: i32) -> NaiveDate {
+    let a = year % 19;
+    let b = year / 100;
+    let c = year % 100;
+    let d = b / 4;
+    let e = b % 4;
+    let f = (b + 8) / 25;
+    let g = (b - f + 1) / 3;
+    let h = (19 * a + b - d - g + 15) % 30;
+    let i = c / 4;
+    let k = c % 4;
+    let l = (32 + 2 * e + 2 * i - h - k) % 7;
+    let m = (a + 11 * h + 22 * l) / 451;
+    let month = (h + l - 7 * m + 114) / 31;
+    let day = (h + l - 7 * m + 114) % 31 + 1;
+    NaiveDate::from_ymd(year, month as u32, day as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_calculates_easter_sunday() {
+        assert_eq!(easter(2020), NaiveDate::from_ymd(2020, 4, 12));
+        assert_eq!(easter(2024), NaiveDate::from_ymd(2024, 3, 31));
+        assert_eq!(easter(2025), NaiveDate::from_ymd(2025, 4, 20));
+    }
+
+    #[test]
+    fn it_gives_holidays_of_a_state() {
+        let bavarian = holidays("by".parse().unwrap(), 2020);
+        let names = bavarian
+            .iter()
+            .map(|(_date, name)| *name)
+            .collect::<Vec<_>>();
+
+        assert_eq!(bavarian.len(), 12);
+        assert!(bavarian.contains(&(NaiveDate::from_ymd(2020, 6, 11), "Fronleichnam")));
+        assert!(!names.contains(&"Reformationstag"));
+        assert!(holidays("SN".parse().unwrap(), 2020)
+            .contains(&(NaiveDate::from_ymd(2020, 11, 18), "Buß- und Bettag")));
+        assert!("XX".parse::<State>().is_err());
+    }
+}
@@ -0,0 +1,154 @@
+use crate::calendar::{
+    calendar_error::CalendarError,
+    event::{all_day_events, AllDayEvent},
+};
+use crate::holidays::{
+    german::{self, State},
+    holidays_file_error::HolidaysFileError,
+};
+use chrono::{Datelike, NaiveDate};
+use serde::Deserialize;
+use std::path::Path;
+
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct HolidayCalendar {
+    state: Option<State>,
+    events: Vec<AllDayEvent>,
+}
+
+#[derive(Deserialize)]
+struct Holiday {
+    date: NaiveDate,
+    name: String,
+}
+
+impl HolidayCalendar {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_state(mut self, state: State) -> Self {
+        self.state = Some(state);
+        self
+    }
+
+    pub fn with_events(mut self, events: Vec<AllDayEvent>) -> Self {
+        self.events.extend(events);
+        self
+    }
+
+    pub fn parse(
+        text: &str,
+        file_name: &str,
+    ) -> Result<(Vec<AllDayEvent>, Vec<CalendarError>), HolidaysFileError> {
+        let extension = Path::new(file_name)
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .map(str::to_lowercase);
+        match extension.as_deref() {
+            Some("ics") | Some("ical") => all_day_events(text).map_err(HolidaysFileError::Calendar),
+            _ if text.trim_start().starts_with("BEGIN:VCALENDAR") => {
+                all_day_events(text).map_err(HolidaysFileError::Calendar)
+            }
+            _ => {
+                let holidays: Vec<Holiday> =
+                    serde_json::from_str(text).map_err(HolidaysFileError::Json)?;
+                Ok((
+                    holidays
+                        .into_iter()
+                        .map(|holiday| AllDayEvent::on(holiday.date, &holiday.name))
+                        .collect(),
+                    vec![],
+                ))
+            }
+        }
+    }
+
+    pub fn name(&self, date: NaiveDate) -> Option<String> {
+        if let Some(event) = self.events.iter().rev().find(|event| event.covers(date)) {
+            return Some(event.summary().to_string());
+        }
+        self.state.and_then(|state| {
+            german::holidays(state, date.year())
+                .into_iter()
+                .find(|(day, _name)| *day == date)
+                .map(|(_day, name)| name.to_string())
+        })
+    }
+
+    pub fn is_holiday(&self, date: NaiveDate) -> bool {
+        self.name(date).is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_combines_state_holidays_and_holidays_from_files() {
+        let (events, _errors) = HolidayCalendar::parse(
+            r#"[{ "date": "2020-12-24", "name": "Christmas Eve" }]"#,
+            "company.json",
+        )
+        .unwrap();
+        let holidays = HolidayCalendar::new()
+            .with_state("NW".parse().unwrap())
+            .with_events(events);
+
+        assert_eq!(
+            holidays.name(NaiveDate::from_ymd(2020, 12, 24)),
+            Some("Christmas Eve".to_string())
+        );
+        assert_eq!(
+            holidays.name(NaiveDate::from_ymd(2020, 11, 1)),
+            Some("Allerheiligen".to_string())
+        );
+        assert!(!holidays.is_holiday(NaiveDate::from_ymd(2020, 11, 2)));
+    }
+
+    #[test]
+    fn it_repeats_holidays_of_calendar_files() {
+        let text = "BEGIN:VCALENDAR\r
+BEGIN:VEVENT\r
+DTSTART;VALUE=DATE:20191224\r
+DURATION:P3D\r
+RRULE:FREQ=YEARLY\r
+SUMMARY:Christmas\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+DTSTART;VALUE=DATE:20191231\r
+RRULE:FREQ=YEARLY;UNTIL=20201231\r
+EXDATE;VALUE=DATE:20201231\r
+SUMMARY:New Year's Eve\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+DTSTART;VALUE=DATE:20200101\r
+RRULE:FREQ=YEARLY;BYSETPOS=1\r
+SUMMARY:Unknown\r
+END:VEVENT\r
+END:VCALENDAR\r
+";
+
+        let (events, errors) = HolidayCalendar::parse(text, "company.ics").unwrap();
+        let holidays = HolidayCalendar::new().with_events(events);
+
+        assert_eq!(
+            holidays.name(NaiveDate::from_ymd(2021, 12, 26)),
+            Some("Christmas".to_string())
+        );
+        assert!(holidays.is_holiday(NaiveDate::from_ymd(2019, 12, 24)));
+        assert!(!holidays.is_holiday(NaiveDate::from_ymd(2021, 12, 27)));
+        assert!(!holidays.is_holiday(NaiveDate::from_ymd(2019, 12, 23)));
+        assert!(holidays.is_holiday(NaiveDate::from_ymd(2019, 12, 31)));
+        assert!(!holidays.is_holiday(NaiveDate::from_ymd(2020, 12, 31)));
+        assert!(!holidays.is_holiday(NaiveDate::from_ymd(2021, 12, 31)));
+        assert_eq!(
+            errors,
+            vec![CalendarError::UnsupportedRecurrence(
+                "Unknown".to_string(),
+                "FREQ=YEARLY;BYSETPOS=1".to_string()
+            )]
+        );
+    }
+}
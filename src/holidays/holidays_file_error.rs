@@ -0,0 +1,17 @@
+use crate::calendar::calendar_error::CalendarError;
+use std::fmt;
+
+#[derive(Debug)]
+pub enum HolidaysFileError {
+    Json(serde_json::Error),
+    Calendar(CalendarError),
+}
+
+impl fmt::Display for HolidaysFileError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self {
+            Self::Json(error) => write!(f, "Invalid holidays json: {}", error),
+            Self::Calendar(error) => write!(f, "Invalid holidays calendar: {}", error),
+        }
+    }
+}
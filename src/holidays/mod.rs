@@ -0,0 +1,3 @@
+pub mod german;
+pub mod holiday_calendar;
+pub mod holidays_file_error;
@@ -0,0 +1,70 @@
+use chrono::NaiveDate;
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Issue {
+    key: String,
+    status: String,
+    resolved: Option<NaiveDate>,
+}
+
+impl Issue {
+    pub fn new(key: String, status: String, resolved: Option<NaiveDate>) -> Self {
+        Self {
+            key,
+            status,
+            resolved,
+        }
+    }
+
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    pub fn status(&self) -> &str {
+        &self.status
+    }
+
+    pub fn resolved(&self) -> Option<NaiveDate> {
+        self.resolved
+    }
+
+    pub fn project(&self) -> &str {
+        project_of(&self.key)
+    }
+
+    pub fn is_closed_before(&self, date: NaiveDate) -> bool {
+        self.resolved.is_some_and(|resolved| resolved < date)
+    }
+}
+
+pub fn project_of(key: &str) -> &str {
+    key.rsplit_once('-')
+        .map_or(key, |(project, _number)| project)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn issue(resolved: Option<NaiveDate>) -> Issue {
+        Issue::new("TV-12".to_string(), "Done".to_string(), resolved)
+    }
+
+    #[test]
+    fn it_takes_project_from_key() {
+        assert_eq!(issue(None).project(), "TV");
+    }
+
+    #[test]
+    fn it_is_closed_only_after_its_resolution_day() {
+        let issue = issue(Some(NaiveDate::from_ymd(2020, 1, 12)));
+
+        assert!(!issue.is_closed_before(NaiveDate::from_ymd(2020, 1, 12)));
+        assert!(issue.is_closed_before(NaiveDate::from_ymd(2020, 1, 13)));
+    }
+
+    #[test]
+    fn it_is_never_closed_when_unresolved() {
+        assert!(!issue(None).is_closed_before(NaiveDate::from_ymd(2030, 1, 1)));
+    }
+}
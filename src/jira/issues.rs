@@ -0,0 +1,190 @@
+use crate::jira::{issue::Issue, issues_file_error::IssuesFileError};
+use chrono::{DateTime, NaiveDate, NaiveDateTime};
+use serde::Deserialize;
+use std::path::Path;
+
+const DATE_TIME_FORMATS: [&str; 4] = [
+    "%Y-%m-%dT%H:%M:%S%.f%z",
+    "%Y-%m-%d %H:%M",
+    "%d/%b/%y %I:%M %p",
+    "%d.%m.%Y %H:%M",
+];
+
+#[derive(Debug, PartialEq, Default)]
+pub struct Issues {
+    issues: Vec<Issue>,
+}
+
+#[derive(Deserialize)]
+struct JiraExport {
+    issues: Vec<JiraIssue>,
+}
+
+#[derive(Deserialize)]
+struct JiraIssue {
+    key: String,
+    fields: JiraFields,
+}
+
+#[derive(Deserialize)]
+struct JiraFields {
+    status: JiraStatus,
+    resolutiondate: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct JiraStatus {
+    name: String,
+}
+
+impl Issues {
+    pub fn new(issues: Vec<Issue>) -> Self {
+        Self { issues }
+    }
+
+    pub fn parse(text: &str, file_name: &str) -> Result<Self, IssuesFileError> {
+        let extension = Path::new(file_name)
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .map(str::to_lowercase);
+        match extension.as_deref() {
+            Some("json") => Self::from_json(text),
+            Some("csv") => Self::from_csv(text),
+            _ if text.trim_start().starts_with('{') => Self::from_json(text),
+            _ => Self::from_csv(text),
+        }
+    }
+
+    pub fn from_json(text: &str) -> Result<Self, IssuesFileError> {
+        let export: JiraExport = serde_json::from_str(text).map_err(IssuesFileError::Json)?;
+        export
+            .issues
+            .into_iter()
+            .map(|issue| {
+                let resolved = resolved(&issue.key, issue.fields.resolutiondate.as_deref())?;
+                Ok(Issue::new(issue.key, issue.fields.status.name, resolved))
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map(Self::new)
+    }
+
+    pub fn from_csv(text: &str) -> Result<Self, IssuesFileError> {
+        let mut reader = csv::Reader::from_reader(text.as_bytes());
+        let headers = reader.headers().map_err(IssuesFileError::Csv)?.clone();
+        let column = |name: &'static str| {
+            headers
+                .iter()
+                .position(|header| header.trim() == name)
+                .ok_or(IssuesFileError::MissingColumn(name))
+        };
+        let (key, status, resolution) =
+            (column("Issue key")?, column("Status")?, column("Resolved")?);
+
+        reader
+            .records()
+            .map(|record| {
+                let record = record.map_err(IssuesFileError::Csv)?;
+                let field = |index: usize| record.get(index).unwrap_or("").trim().to_string();
+                let resolved = resolved(&field(key), Some(&field(resolution)))?;
+                Ok(Issue::new(field(key), field(status), resolved))
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map(Self::new)
+    }
+
+    pub fn get(&self, key: &str) -> Option<&Issue> {
+        self.issues.iter().find(|issue| issue.key() == key)
+    }
+
+    pub fn has_project(&self, project: &str) -> bool {
+        self.issues.iter().any(|issue| issue.project() == project)
+    }
+}
+
+fn resolved(key: &str, date: Option<&str>) -> Result<Option<NaiveDate>, IssuesFileError> {
+    let date = match date.map(str::trim) {
+        None | Some("") => return Ok(None),
+        Some(date) => date,
+    };
+    DATE_TIME_FORMATS
+        .iter()
+        .find_map(|format| {
+            DateTime::parse_from_str(date, format)
+                .map(|date| date.naive_local())
+                .or_else(|_| NaiveDateTime::parse_from_str(date, format))
+                .ok()
+        })
+        .map(|date| Some(date.date()))
+        .ok_or_else(|| IssuesFileError::InvalidDate(key.to_string(), date.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn expected_issues() -> Issues {
+        Issues::new(vec![
+            Issue::new(
+                "TV-1".to_string(),
+                "Done".to_string(),
+                Some(NaiveDate::from_ymd(2020, 1, 10)),
+            ),
+            Issue::new("TV-2".to_string(), "In Progress".to_string(), None),
+        ])
+    }
+
+    #[test]
+    fn it_parses_json_export() {
+        let json = r#"
+{
+  "issues": [
+    {
+      "key": "TV-1",
+      "fields": {
+        "summary": "Improve Readme",
+        "status": { "name": "Done" },
+        "resolutiondate": "2020-01-10T17:12:00.000+0100"
+      }
+    },
+    {
+      "key": "TV-2",
+      "fields": { "status": { "name": "In Progress" }, "resolutiondate": null }
+    }
+  ]
+}
+"#;
+
+        let issues = Issues::parse(json, "issues.json").unwrap();
+
+        assert_eq!(issues, expected_issues());
+    }
+
+    #[test]
+    fn it_parses_csv_export() {
+        let csv = r#"Summary,Issue key,Issue id,Status,Resolved
+"Improve Readme, again",TV-1,10001,Done,10/Jan/20 5:12 PM
+Build stuff,TV-2,10002,In Progress,
+"#;
+
+        let issues = Issues::parse(csv, "issues.csv").unwrap();
+
+        assert_eq!(issues, expected_issues());
+    }
+
+    #[test]
+    fn it_needs_the_key_column_in_csv() {
+        let issues = Issues::parse("Summary,Status,Resolved\n", "issues.csv");
+
+        assert!(matches!(
+            issues,
+            Err(IssuesFileError::MissingColumn("Issue key"))
+        ));
+    }
+
+    #[test]
+    fn it_reports_invalid_resolution_dates() {
+        let issues = Issues::parse("Issue key,Status,Resolved\nTV-1,Done,yesterday\n", "issues");
+
+        assert!(matches!(issues, Err(IssuesFileError::InvalidDate(_, _))));
+    }
+}
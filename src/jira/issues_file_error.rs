@@ -0,0 +1,22 @@
+use std::fmt;
+
+#[derive(Debug)]
+pub enum IssuesFileError {
+    Json(serde_json::Error),
+    Csv(csv::Error),
+    MissingColumn(&'static str),
+    InvalidDate(String, String),
+}
+
+impl fmt::Display for IssuesFileError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self {
+            Self::Json(error) => write!(f, "Invalid Jira json: {}", error),
+            Self::Csv(error) => write!(f, "Invalid Jira csv: {}", error),
+            Self::MissingColumn(column) => write!(f, "Jira csv has no column '{}'", column),
+            Self::InvalidDate(key, date) => {
+                write!(f, "Issue {} has an invalid resolution date '{}'", key, date)
+            }
+        }
+    }
+}
@@ -0,0 +1,5 @@
+pub mod issue;
+pub mod issues;
+pub mod issues_file_error;
+pub mod ticket_error;
+pub mod ticket_rule;
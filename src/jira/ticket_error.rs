@@ -0,0 +1,43 @@
+use chrono::NaiveDate;
+use std::fmt;
+
+#[derive(Debug, PartialEq)]
+pub enum TicketError {
+    Unknown(String),
+    Closed {
+        key: String,
+        status: String,
+        resolved: NaiveDate,
+    },
+    WrongProject {
+        key: String,
+        project: String,
+    },
+}
+
+impl fmt::Display for TicketError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let error_name = "TicketError";
+        match &self {
+            Self::Unknown(key) => write!(
+                f,
+                "{:<10} | {}: Issue {} does not exist",
+                "Description", error_name, key
+            ),
+            Self::Closed {
+                key,
+                status,
+                resolved,
+            } => write!(
+                f,
+                "{:<10} | {}: Issue {} was closed ({}) on {}",
+                "Description", error_name, key, status, resolved
+            ),
+            Self::WrongProject { key, project } => write!(
+                f,
+                "{:<10} | {}: Issue {} does not belong to project {}",
+                "Description", error_name, key, project
+            ),
+        }
+    }
+}
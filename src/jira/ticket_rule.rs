@@ -0,0 +1,166 @@
+use crate::jira::{issue::project_of, issues::Issues, ticket_error::TicketError};
+use crate::line_error::LineError;
+use crate::projects_and_tasks::{list_with_names::ListWithNames, named::Named, projects::Projects};
+use crate::validation::{activity::Activity, rule::Rule};
+use regex::Regex;
+use std::collections::HashMap;
+
+const KEY_PATTERN: &str = r"\b[A-Z][A-Z0-9_]+-[0-9]+\b";
+
+pub struct TicketRule {
+    issues: Issues,
+    jira_projects: HashMap<String, Vec<String>>,
+    key_pattern: Regex,
+}
+
+impl TicketRule {
+    pub fn new(issues: Issues, projects: &Projects) -> Self {
+        Self {
+            issues,
+            jira_projects: projects
+                .items()
+                .map(|project| (project.name().to_string(), project.jira_projects().to_vec()))
+                .collect(),
+            key_pattern: Regex::new(KEY_PATTERN).unwrap(),
+        }
+    }
+
+    fn check_activity(&self, activity: &Activity) -> Vec<TicketError> {
+        let date = activity.start().date().date().naive_local();
+        let jira_projects = self
+            .jira_projects
+            .get(activity.project().name())
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+
+        self.key_pattern
+            .find_iter(activity.description().text())
+            .map(|key| key.as_str())
+            .filter(|key| self.is_known_project(project_of(key)))
+            .filter_map(|key| match self.issues.get(key) {
+                None => Some(TicketError::Unknown(key.to_string())),
+                Some(issue) if issue.is_closed_before(date) => Some(TicketError::Closed {
+                    key: key.to_string(),
+                    status: issue.status().to_string(),
+                    resolved: issue.resolved().unwrap(),
+                }),
+                Some(issue)
+                    if !jira_projects.is_empty()
+                        && !jira_projects
+                            .iter()
+                            .any(|project| project == issue.project()) =>
+                {
+                    Some(TicketError::WrongProject {
+                        key: key.to_string(),
+                        project: activity.project().name().to_string(),
+                    })
+                }
+                Some(_) => None,
+            })
+            .collect()
+    }
+
+    fn is_known_project(&self, project: &str) -> bool {
+        self.issues.has_project(project)
+            || self
+                .jira_projects
+                .values()
+                .any(|jira_projects| jira_projects.iter().any(|known| known == project))
+    }
+}
+
+impl Rule for TicketRule {
+    fn check(&self, activities: &[(usize, Activity)]) -> Vec<LineError> {
+        activities
+            .iter()
+            .flat_map(|(line_number, activity)| {
+                self.check_activity(activity)
+                    .into_iter()
+                    .map(move |error| LineError::new(*line_number, error.to_string()))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::jira::issue::Issue;
+    use crate::projects_and_tasks::{project::ProjectWithTasksBuilder, projects::ProjectsBuilder};
+    use crate::test_fixtures::ActivityBuilder;
+    use chrono::NaiveDate;
+
+    fn rule() -> TicketRule {
+        let issues = Issues::new(vec![
+            Issue::new("TV-1".to_string(), "Open".to_string(), None),
+            Issue::new(
+                "TV-2".to_string(),
+                "Done".to_string(),
+                Some(NaiveDate::from_ymd(2020, 1, 10)),
+            ),
+            Issue::new("OPS-3".to_string(), "Open".to_string(), None),
+        ]);
+        let projects = ProjectsBuilder::new()
+            .with_projects(vec![ProjectWithTasksBuilder::new()
+                .with_name("Timeval".to_string())
+                .with_jira_projects(vec!["TV".to_string()])
+                .build()])
+            .build();
+        TicketRule::new(issues, &projects)
+    }
+
+    fn activity(description: &str) -> Activity {
+        ActivityBuilder::new()
+            .with_project_name("Timeval")
+            .with_description(description)
+            .build()
+    }
+
+    #[test]
+    fn it_accepts_open_issues_of_the_booked_project() {
+        let errors = rule().check_activity(&activity("Work on TV-1"));
+
+        assert_eq!(errors, vec![]);
+    }
+
+    #[test]
+    fn it_ignores_keys_of_unknown_jira_projects() {
+        let errors = rule().check_activity(&activity("Read ISO-8601 and RFC-3339"));
+
+        assert_eq!(errors, vec![]);
+    }
+
+    #[test]
+    fn it_reports_unknown_closed_and_foreign_issues() {
+        let errors = rule().check_activity(&activity("TV-7, TV-2 and OPS-3"));
+
+        assert_eq!(
+            errors,
+            vec![
+                TicketError::Unknown("TV-7".to_string()),
+                TicketError::Closed {
+                    key: "TV-2".to_string(),
+                    status: "Done".to_string(),
+                    resolved: NaiveDate::from_ymd(2020, 1, 10)
+                },
+                TicketError::WrongProject {
+                    key: "OPS-3".to_string(),
+                    project: "Timeval".to_string()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn it_reports_errors_at_the_line_of_the_activity() {
+        let errors = rule().check(&[(4, activity("TV-7"))]);
+
+        assert_eq!(
+            errors,
+            vec![LineError::new(
+                4,
+                TicketError::Unknown("TV-7".to_string()).to_string()
+            )]
+        );
+    }
+}
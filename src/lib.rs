@@ -1,47 +1,182 @@
+use crate::fix::{
+    align::align,
+    normalize::{self, Reference},
+    rewrite::rewrite,
+    Fixes, Slot,
+};
 use crate::line_error::LineError;
-use crate::parser::activity_line::ActivityLine;
-use crate::projects_and_tasks::{harvest::projects::HarvestProjectAssignments, projects::Projects};
-use crate::validation::activity::Activity;
+use crate::parser::input_format::InputFormat;
+use crate::projects_and_tasks::{
+    lint_error::LintError,
+    merge_error::MergeError,
+    projects::{Projects, ProjectsBuilder},
+    projects_file::ProjectsFile,
+    projects_file_error::ProjectsFileError,
+};
+use crate::validation::{activity::Activity, rule::Rules};
 
+pub mod balance;
+pub mod calendar;
+pub mod config;
+pub mod export;
+pub mod fix;
+pub mod harvest_api;
+pub mod holidays;
+pub mod jira;
 mod line_error;
-mod parser;
+pub mod parser;
 pub mod projects_and_tasks;
-mod validation;
+pub mod rules;
+#[cfg(test)]
+mod test_fixtures;
+pub mod validation;
+
+pub fn validate(text: &str, format: &InputFormat, projects: &Projects, rules: &Rules) -> String {
+    combine_errors(check(text, format, projects, rules).1)
+}
+
+pub fn parse_activities(
+    text: &str,
+    format: &InputFormat,
+    projects: &Projects,
+    rules: &Rules,
+) -> Result<Vec<Activity>, String> {
+    let (activities, errors) = check(text, format, projects, rules);
+    if !errors.is_empty() {
+        return Err(combine_errors(errors));
+    }
+    Ok(activities
+        .into_iter()
+        .map(|(_no, activity)| activity)
+        .collect())
+}
+
+pub fn fix(
+    text: &str,
+    format: &InputFormat,
+    projects: &Projects,
+    fixes: &Fixes,
+) -> Result<(String, String), String> {
+    if !format.is_editable() {
+        return Err("Only org, markdown and csv timesheets can be fixed".to_string());
+    }
+    let mut slots = vec![];
+    let mut errors = vec![];
+    for (no, fields) in format.records(text, projects) {
+        match fields.and_then(|fields| {
+            fields
+                .parse(projects)
+                .map(|activity| Slot::new(no, &fields, &activity))
+        }) {
+            Ok(slot) => slots.push(slot),
+            Err(error) => errors.extend(error.at_line(no)),
+        }
+    }
+    if !errors.is_empty() {
+        return Err(combine_errors(errors));
+    }
+    slots.sort_by_key(|slot| slot.start);
+    let mut errors = fixes.apply(&mut slots);
+    let (fixed, rewrite_errors) = rewrite(text, format, &slots);
+    errors.extend(rewrite_errors);
+    errors.sort_by_key(LineError::line_number);
+    Ok((fixed, combine_errors(errors)))
+}
 
-pub fn validate(text: &str, projects: &Projects) -> String {
-    let (_activities, errors) = parse(text, projects);
-    combine_errors(errors)
+pub fn normalize(
+    text: &str,
+    format: &InputFormat,
+    projects: &Projects,
+    reference: Reference,
+) -> Result<(String, String), String> {
+    if !format.is_editable() {
+        return Err("Only org, markdown and csv timesheets can be normalized".to_string());
+    }
+    let (normalized, errors) = normalize::normalize(text, format, projects, reference);
+    Ok((normalized, combine_errors(errors)))
+}
+
+pub fn format_timesheet(text: &str, format: &InputFormat) -> Result<String, String> {
+    match format {
+        InputFormat::Org(table) => Ok(align(text, table.description_takes_rest)),
+        _ => Err("Only org timesheets can be formatted".to_string()),
+    }
 }
 
-type ActivityParseResult = Result<Activity, Vec<LineError>>;
+fn check(
+    text: &str,
+    format: &InputFormat,
+    projects: &Projects,
+    rules: &Rules,
+) -> (Vec<(usize, Activity)>, Vec<LineError>) {
+    let (activities, mut errors) = parse(text, format, projects);
+    errors.extend(rules.check(&activities));
+    errors.sort_by_key(LineError::line_number);
+    (activities, errors)
+}
 
-fn parse(text: &str, projects: &Projects) -> (Vec<ActivityParseResult>, Vec<ActivityParseResult>) {
-    text.lines()
-        .enumerate()
-        .filter(|(_no, line)| !line.is_empty())
-        .map(|(no, line)| (no, ActivityLine::new(line)))
-        .map(|(no, activity_line)| (no, activity_line.parse(projects)))
-        .map(|(no, activity)| activity.map_err(|e| e.at_line(no)))
-        .partition(Result::is_ok)
+fn parse(
+    text: &str,
+    format: &InputFormat,
+    projects: &Projects,
+) -> (Vec<(usize, Activity)>, Vec<LineError>) {
+    let mut activities = vec![];
+    let mut errors = vec![];
+    for (no, fields) in format.records(text, projects) {
+        match fields.and_then(|fields| fields.parse(projects)) {
+            Ok(activity) => activities.push((no, activity)),
+            Err(error) => errors.extend(error.at_line(no)),
+        }
+    }
+    (activities, errors)
 }
 
-fn combine_errors(errors: Vec<ActivityParseResult>) -> String {
+fn combine_errors(errors: Vec<LineError>) -> String {
     errors
         .into_iter()
-        .map(Result::unwrap_err)
-        .flatten()
         .map(|error| error.to_string())
         .collect::<Vec<_>>()
         .join("\n")
 }
 
+pub fn parse_projects(text: &str, file_name: &str) -> Result<Projects, ProjectsFileError> {
+    let projects_file = ProjectsFile::parse(text, file_name)?;
+    Ok(projects_file.into_projects())
+}
+
+pub fn check_projects(
+    text: &str,
+    file_name: &str,
+) -> Result<(Projects, Vec<LintError>), ProjectsFileError> {
+    let projects_file = ProjectsFile::parse(text, file_name)?;
+    let errors = projects_file.lint();
+    Ok((projects_file.into_projects(), errors))
+}
+
+pub fn merge_projects(projects: Vec<Projects>) -> Result<Projects, Vec<MergeError>> {
+    let mut projects = projects.into_iter();
+    let mut merged = projects
+        .next()
+        .unwrap_or_else(|| ProjectsBuilder::empty().build());
+    let errors = projects
+        .flat_map(|other| merged.merge(other))
+        .collect::<Vec<_>>();
+
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+    Ok(merged)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::{CsvColumns, TableConfig};
     use crate::projects_and_tasks::{
         project::ProjectWithTasksBuilder, projects::ProjectsBuilder, task::TaskBuilder,
         tasks::TasksBuilder,
     };
+    use crate::validation::rule::Rule;
 
     fn projects() -> Projects {
         ProjectsBuilder::new()
@@ -69,7 +204,12 @@ mod tests {
  | A2020-01-12T08:00:00 | 2020-01-12T08:30:00  | Project | Bla  | Description | 
 "#;
 
-        let errors = validate(lines, &projects());
+        let errors = validate(
+            lines,
+            &InputFormat::Org(TableConfig::default()),
+            &projects(),
+            &Rules::new(),
+        );
 
         let expected_line_starts = vec!["1Start", "1End", "1Project", "6Start", "6Task"];
         let line_starts = errors
@@ -83,9 +223,56 @@ mod tests {
             .collect::<Vec<_>>();
         assert_eq!(line_starts, expected_line_starts);
     }
-}
 
-pub fn parse_projects(text: &str) -> Result<Projects, serde_json::Error> {
-    let harvest_projects = HarvestProjectAssignments::from(text)?;
-    Ok(harvest_projects.to_projects())
+    struct EveryActivity;
+
+    impl Rule for EveryActivity {
+        fn check(&self, activities: &[(usize, Activity)]) -> Vec<LineError> {
+            activities
+                .iter()
+                .map(|(no, _activity)| LineError::new(*no, "Rule".to_string()))
+                .collect()
+        }
+    }
+
+    #[test]
+    fn it_sorts_rule_errors_between_parse_errors() {
+        let lines = r#"| 2020-01-12T08:00:00 | 2020-01-12T08:30:00 | Project | Task | Description |
+| A2020-01-12T08:30:00 | 2020-01-12T09:00:00 | Project | Task | Description |
+| 2020-01-12T09:00:00 | 2020-01-12T09:30:00 | Project | Task | Description |"#;
+
+        let errors = validate(
+            lines,
+            &InputFormat::Org(TableConfig::default()),
+            &projects(),
+            &Rules::new().with(EveryActivity),
+        );
+
+        let line_starts = errors
+            .lines()
+            .map(|line| {
+                line.split("|")
+                    .map(|part| part.trim())
+                    .take(3)
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(line_starts, vec!["0Rule", "1Start", "2Rule"]);
+    }
+
+    #[test]
+    fn it_validates_csv_timesheets() {
+        let lines = r#"Start,End,Project,Task,Description
+2020-01-12T08:00:00,2020-01-12T08:30:00,Project,Task,"Fix a | b"
+A2020-01-12T08:30:00,2020-01-12T09:00:00,Project,Task,Description"#;
+        let format = InputFormat::Csv {
+            delimiter: b',',
+            columns: CsvColumns::default(),
+        };
+
+        let errors = validate(lines, &format, &projects(), &Rules::new());
+
+        assert_eq!(errors.lines().count(), 1);
+        assert!(errors.starts_with(" |   2 | Start"));
+    }
 }
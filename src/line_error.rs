@@ -13,4 +13,8 @@ impl LineError {
     pub fn new(line_number: usize, message: String) -> Self {
         Self(line_number, message)
     }
+
+    pub fn line_number(&self) -> usize {
+        self.0
+    }
 }
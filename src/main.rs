@@ -1,24 +1,445 @@
-use clap::{load_yaml, App};
+use chrono::{Duration, Local, NaiveDate, Utc};
+use clap::{load_yaml, App, ArgMatches};
+use std::path::{Path, PathBuf};
 use std::process;
-use timing::projects_and_tasks::projects::Projects;
+use timing::balance::{model::WorkingTimeModel, report::Report};
+use timing::calendar::{drafts::drafts, event::events};
+use timing::config::{Config, HarvestConfig};
+use timing::export::{ics::ics, timeclock::timeclock};
+use timing::fix::{break_fix::BreakFix, normalize::Reference, rounding_fix::RoundingFix, Fixes};
+use timing::harvest_api::{
+    api_error::ApiError,
+    cache::{CacheMode, Origin, ProjectsCache},
+    client::Client,
+    diff::{self, RemoteActivity},
+    ledger::Ledger,
+    sync::{self, LocalEntry},
+};
+use timing::holidays::holiday_calendar::HolidayCalendar;
+use timing::jira::{issues::Issues, ticket_rule::TicketRule};
+use timing::parser::input_format::InputFormat;
+use timing::projects_and_tasks::{lint_error::LintError, projects::Projects};
+use timing::rules::{
+    break_rule::BreakRule, gap_rule::GapRule, rest_rule::RestRule, rounding::Roundings,
+    rounding_rule::RoundingRule, weekend_rule::WeekendRule, working_time_rule::WorkingTimeRule,
+};
+use timing::validation::rule::Rules;
 
 fn main() {
     let yaml = load_yaml!("cli.yaml");
     let matches = App::from(yaml).get_matches();
+    let config = self::config(matches.value_of("config"));
 
+    if let Some(matches) = matches.subcommand_matches("check-projects") {
+        self::check_projects(&self::projects_files(matches, &config));
+        return;
+    }
+    if let Some(matches) = matches.subcommand_matches("push") {
+        self::push(matches, &config);
+        return;
+    }
+    if let Some(matches) = matches.subcommand_matches("diff") {
+        self::diff(matches, &config);
+        return;
+    }
+    if let Some(matches) = matches.subcommand_matches("import-calendar") {
+        self::import_calendar(matches, &config);
+        return;
+    }
+    if let Some(matches) = matches.subcommand_matches("export") {
+        self::export(matches, &config);
+        return;
+    }
+    if let Some(matches) = matches.subcommand_matches("balance") {
+        self::balance(matches, &config);
+        return;
+    }
+    if let Some(matches) = matches.subcommand_matches("fmt") {
+        self::fmt(matches, &config);
+        return;
+    }
+    if let Some(matches) = matches.subcommand_matches("normalize") {
+        self::normalize(matches, &config);
+        return;
+    }
+
+    let input = matches.value_of("INPUT").unwrap();
+    let projects = self::projects(&matches, &config);
+    let format = self::format(input, &matches, &config);
+    let mut text = self::timings(input);
+    if matches.is_present("fix") || matches.is_present("diff") {
+        let (fixed, errors) =
+            timing::fix(&text, &format, &projects, &self::fixes(&config, &projects))
+                .unwrap_or_else(|errors| {
+                    println!("{}", errors);
+                    eprintln!("Fix: Refusing to fix a timesheet with errors");
+                    process::exit(1);
+                });
+        if !errors.is_empty() {
+            eprintln!("{}", errors);
+            eprintln!("Fix: Some lines could not be fixed");
+        }
+        if matches.is_present("diff") {
+            print!("{}", timing::fix::diff::diff(input, &text, &fixed));
+            if !errors.is_empty() {
+                process::exit(1);
+            }
+            return;
+        }
+        std::fs::write(input, &fixed).unwrap_or_else(|err| {
+            eprintln!("Timesheet {}: {}", input, err);
+            process::exit(1);
+        });
+        text = fixed;
+    }
     println!(
         "{}",
         timing::validate(
-            &self::timings(matches.value_of("INPUT").unwrap()),
-            &self::projects(
-                matches
-                    .value_of("projects")
-                    .unwrap_or("input/projects.json")
-            )
+            &text,
+            &format,
+            &projects,
+            &self::rules(&matches, &config, &projects)
         )
     );
 }
 
+fn check_projects(files: &[PathBuf]) {
+    let mut valid = true;
+    let mut projects = vec![];
+    for file in files {
+        let (file_projects, errors) = self::parse_projects_file(file);
+        for error in &errors {
+            println!("{}: {}", file.display(), error);
+        }
+        valid &= errors.is_empty();
+        projects.push(file_projects);
+    }
+    if let Err(errors) = timing::merge_projects(projects) {
+        for error in &errors {
+            println!("{}", error);
+        }
+        valid = false;
+    }
+    if !valid {
+        process::exit(1);
+    }
+}
+
+fn push(matches: &ArgMatches, config: &Config) {
+    let input = matches.value_of("INPUT").unwrap();
+    let projects = self::projects(matches, config);
+    let rules = self::ticket_rules(matches, config, &projects);
+    let activities = timing::parse_activities(
+        &self::timings(input),
+        &self::format(input, matches, config),
+        &projects,
+        &rules,
+    )
+    .unwrap_or_else(|errors| {
+        println!("{}", errors);
+        eprintln!("Push: Refusing to push a timesheet with errors");
+        process::exit(1);
+    });
+    let entries = activities
+        .iter()
+        .map(LocalEntry::from)
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap_or_else(|err| {
+            eprintln!("Push: {}", err);
+            process::exit(1);
+        });
+
+    let ledger_file = matches
+        .value_of("ledger")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(format!("{}.sync.json", input)));
+    let mut ledger = self::ledger(&ledger_file);
+    let actions = sync::plan(entries, &ledger, matches.is_present("delete"));
+
+    if matches.is_present("dry-run") {
+        for action in &actions {
+            println!("{}", action);
+        }
+        return;
+    }
+
+    let client = Client::new(&self::harvest_config(config)).unwrap_or_else(|err| {
+        eprintln!("Push: {}", err);
+        process::exit(1);
+    });
+    let results = sync::execute(&client, actions, &mut ledger);
+    std::fs::write(&ledger_file, ledger.to_json()).unwrap_or_else(|err| {
+        eprintln!("Ledger {}: {}", ledger_file.display(), err);
+        process::exit(1);
+    });
+    let results = results.unwrap_or_else(|err| {
+        eprintln!("Push: {}", err);
+        process::exit(1);
+    });
+    for result in results {
+        println!("{}", result);
+    }
+}
+
+fn diff(matches: &ArgMatches, config: &Config) {
+    let input = matches.value_of("INPUT").unwrap();
+    let projects = self::projects(matches, config);
+    let activities = timing::parse_activities(
+        &self::timings(input),
+        &self::format(input, matches, config),
+        &projects,
+        &Rules::new(),
+    )
+    .unwrap_or_else(|errors| {
+        println!("{}", errors);
+        eprintln!("Diff: Refusing to compare a timesheet with errors");
+        process::exit(1);
+    });
+    let dates = activities
+        .iter()
+        .map(|activity| activity.start().date().date().naive_local())
+        .collect::<Vec<_>>();
+    let from = self::date(matches.value_of("from"), dates.iter().min());
+    let to = self::date(matches.value_of("to"), dates.iter().max());
+
+    let client = Client::new(&self::harvest_config(config)).unwrap_or_else(|err| {
+        eprintln!("Diff: {}", err);
+        process::exit(1);
+    });
+    let remote = client
+        .time_entries(from, to)
+        .and_then(|entries| {
+            entries
+                .iter()
+                .map(|entry| {
+                    Ok(RemoteActivity {
+                        id: entry.id,
+                        activity: entry.to_activity(&projects)?,
+                        duration_only: !entry.has_times(),
+                    })
+                })
+                .collect::<Result<Vec<_>, ApiError>>()
+        })
+        .unwrap_or_else(|err| {
+            eprintln!("Diff: {}", err);
+            process::exit(1);
+        });
+    let local = activities
+        .into_iter()
+        .filter(|activity| {
+            let date = activity.start().date().date().naive_local();
+            from <= date && date <= to
+        })
+        .collect();
+
+    for difference in diff::diff(local, remote) {
+        println!("{}", difference);
+    }
+}
+
+fn export(matches: &ArgMatches, config: &Config) {
+    let input = matches.value_of("INPUT").unwrap();
+    let projects = self::projects(matches, config);
+    let activities = timing::parse_activities(
+        &self::timings(input),
+        &self::format(input, matches, config),
+        &projects,
+        &self::ticket_rules(matches, config, &projects),
+    )
+    .unwrap_or_else(|errors| {
+        println!("{}", errors);
+        eprintln!("Export: Refusing to export a timesheet with errors");
+        process::exit(1);
+    });
+
+    match matches.value_of("output-format") {
+        Some("timeclock") => print!("{}", timeclock(&activities)),
+        Some("ics") => print!("{}", ics(&activities, Utc::now())),
+        _ => unreachable!(),
+    }
+}
+
+fn balance(matches: &ArgMatches, config: &Config) {
+    let input = matches.value_of("INPUT").unwrap();
+    let projects = self::projects(matches, config);
+    let activities = timing::parse_activities(
+        &self::timings(input),
+        &self::format(input, matches, config),
+        &projects,
+        &Rules::new(),
+    )
+    .unwrap_or_else(|errors| {
+        println!("{}", errors);
+        eprintln!("Balance: Refusing to balance a timesheet with errors");
+        process::exit(1);
+    });
+    if config.schedules().is_empty() {
+        eprintln!("Balance: Add your working time schedules to the config file");
+        process::exit(1);
+    }
+
+    let model = WorkingTimeModel::new(config.schedules()).with_holidays(self::holidays(config));
+    let days = activities
+        .iter()
+        .map(|activity| activity.start().date().date().naive_local())
+        .collect::<Vec<_>>();
+    let from = self::date(
+        matches.value_of("from"),
+        model.start().as_ref().or_else(|| days.iter().min()),
+    );
+    let to = self::date(matches.value_of("to"), days.iter().max());
+    println!("{}", Report::new(&activities, &model, from, to));
+}
+
+fn import_calendar(matches: &ArgMatches, config: &Config) {
+    let input = matches.value_of("INPUT").unwrap();
+    let projects = self::projects(matches, config);
+    let content = std::fs::read_to_string(input).unwrap_or_else(|err| {
+        eprintln!("Calendar: {}", err);
+        process::exit(1);
+    });
+    let from = matches
+        .value_of("from")
+        .map(|from| self::date(Some(from), None));
+    let to = matches.value_of("to").map(|to| self::date(Some(to), None));
+
+    let (events, errors) = events(&content, to.unwrap_or_else(|| Local::today().naive_local()))
+        .unwrap_or_else(|err| {
+            eprintln!("Problem parsing calendar file {}: {}", input, err);
+            process::exit(1);
+        });
+    for error in errors {
+        eprintln!("{}", error);
+    }
+    let events = events
+        .into_iter()
+        .filter(|event| {
+            let date = event.start.date().naive_local();
+            from.is_none_or(|from| from <= date) && to.is_none_or(|to| date <= to)
+        })
+        .collect::<Vec<_>>();
+    let drafts = drafts(&events, &config.calendar().rules).unwrap_or_else(|err| {
+        eprintln!("Calendar: {}", err);
+        process::exit(1);
+    });
+
+    let text = drafts
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join("\n");
+    println!("{}", text);
+    let errors = timing::validate(
+        &text,
+        &InputFormat::Org(config.table().clone()),
+        &projects,
+        &Rules::new(),
+    );
+    if !errors.is_empty() {
+        eprintln!("{}", errors);
+    }
+}
+
+fn normalize(matches: &ArgMatches, config: &Config) {
+    let input = matches.value_of("INPUT").unwrap();
+    let reference = if matches.is_present("ids") {
+        Reference::Ids
+    } else {
+        Reference::Names
+    };
+    let text = self::timings(input);
+    let (normalized, errors) = timing::normalize(
+        &text,
+        &self::format(input, matches, config),
+        &self::projects(matches, config),
+        reference,
+    )
+    .unwrap_or_else(|err| {
+        eprintln!("Normalize: {}", err);
+        process::exit(1);
+    });
+    if matches.is_present("diff") {
+        print!("{}", timing::fix::diff::diff(input, &text, &normalized));
+    } else {
+        std::fs::write(input, &normalized).unwrap_or_else(|err| {
+            eprintln!("Timesheet {}: {}", input, err);
+            process::exit(1);
+        });
+    }
+    if !errors.is_empty() {
+        eprintln!("{}", errors);
+        eprintln!("Normalize: Some lines were left untouched");
+        process::exit(1);
+    }
+}
+
+fn fmt(matches: &ArgMatches, config: &Config) {
+    let input = matches.value_of("INPUT").unwrap();
+    let text = self::timings(input);
+    let formatted = timing::format_timesheet(&text, &self::format(input, matches, config))
+        .unwrap_or_else(|err| {
+            eprintln!("Fmt: {}", err);
+            process::exit(1);
+        });
+    if matches.is_present("check") {
+        if formatted != text {
+            print!("{}", timing::fix::diff::diff(input, &text, &formatted));
+            eprintln!("Fmt: {} is not formatted", input);
+            process::exit(1);
+        }
+        return;
+    }
+    std::fs::write(input, &formatted).unwrap_or_else(|err| {
+        eprintln!("Timesheet {}: {}", input, err);
+        process::exit(1);
+    });
+}
+
+fn date(argument: Option<&str>, default: Option<&NaiveDate>) -> NaiveDate {
+    match argument {
+        Some(argument) => NaiveDate::parse_from_str(argument, "%Y-%m-%d").unwrap_or_else(|err| {
+            eprintln!("Date {}: {}", argument, err);
+            process::exit(1);
+        }),
+        None => *default.unwrap_or_else(|| {
+            eprintln!("The timesheet is empty, use --from and --to");
+            process::exit(1);
+        }),
+    }
+}
+
+fn ledger(file: &Path) -> Ledger {
+    if !file.exists() {
+        return Ledger::default();
+    }
+    let content = std::fs::read_to_string(file).unwrap_or_else(|err| {
+        eprintln!("Ledger {}: {}", file.display(), err);
+        process::exit(1);
+    });
+    Ledger::from(&content).unwrap_or_else(|err| {
+        eprintln!("Problem parsing ledger file {}: {}", file.display(), err);
+        process::exit(1);
+    })
+}
+
+fn config(file: Option<&str>) -> Config {
+    let file = match file {
+        Some(file) => Path::new(file),
+        None => return Config::default(),
+    };
+    let content = std::fs::read_to_string(file).unwrap_or_else(|err| {
+        eprintln!("Config: {}", err);
+        process::exit(1);
+    });
+
+    Config::from(&content)
+        .unwrap_or_else(|err| {
+            eprintln!("Problem parsing config file: {}", err);
+            process::exit(1);
+        })
+        .relative_to(file.parent().unwrap_or_else(|| Path::new("")))
+}
+
 fn timings(file: &str) -> String {
     std::fs::read_to_string(file).unwrap_or_else(|err| {
         eprintln!("Timings: {}", err);
@@ -26,14 +447,209 @@ fn timings(file: &str) -> String {
     })
 }
 
-fn projects(file: &str) -> Projects {
+fn format(input: &str, matches: &ArgMatches, config: &Config) -> InputFormat {
+    InputFormat::detect(input, matches.value_of("format"), config)
+}
+
+fn projects_files(matches: &ArgMatches, config: &Config) -> Vec<PathBuf> {
+    let mut files = config.projects().to_vec();
+    if let Some(arguments) = matches.values_of("projects") {
+        files.extend(arguments.map(PathBuf::from));
+    }
+    if files.is_empty() && !matches.is_present("harvest-projects") {
+        files.push(PathBuf::from("input/projects.json"));
+    }
+    files
+}
+
+fn projects(matches: &ArgMatches, config: &Config) -> Projects {
+    let mut projects = self::projects_files(matches, config)
+        .iter()
+        .map(|file| self::project_file(file))
+        .collect::<Vec<_>>();
+    if matches.is_present("harvest-projects") {
+        projects.push(self::harvest_projects(matches, config));
+    }
+    timing::merge_projects(projects).unwrap_or_else(|errors| {
+        eprintln!("Problem merging projects files:");
+        for error in errors {
+            eprintln!("{}", error);
+        }
+        process::exit(1);
+    })
+}
+
+fn ticket_rules(matches: &ArgMatches, config: &Config, projects: &Projects) -> Rules {
+    let mut rules = Rules::new();
+    let jira_issues = matches
+        .value_of("jira-issues")
+        .map(Path::new)
+        .or_else(|| config.jira_issues());
+    if let Some(file) = jira_issues {
+        rules = rules.with(TicketRule::new(self::jira_issues(file), projects));
+    }
+    rules
+}
+
+fn rules(matches: &ArgMatches, config: &Config, projects: &Projects) -> Rules {
+    let mut rules = self::ticket_rules(matches, config, projects);
+    if config.rules().breaks.enabled {
+        rules = rules.with(BreakRule::new(config.rules().breaks.clone()));
+    }
+    if config.rules().working_time.enabled {
+        rules = rules.with(WorkingTimeRule::new(config.rules().working_time.clone()));
+    }
+    if config.rules().rest.enabled {
+        rules = rules.with(RestRule::new(config.rules().rest.clone(), projects));
+    }
+    if config.rules().weekends.enabled {
+        rules = rules.with(WeekendRule::new(
+            &config.rules().weekends,
+            self::holidays(config),
+            projects,
+        ));
+    }
+    if config.rules().gaps.enabled {
+        rules = rules.with(GapRule::new(config.rules().gaps.clone()));
+    }
+    if !config.rules().rounding.is_empty() {
+        rules = rules.with(RoundingRule::new(Roundings::new(
+            &config.rules().rounding,
+            projects,
+        )));
+    }
+    rules
+}
+
+fn fixes(config: &Config, projects: &Projects) -> Fixes {
+    let mut fixes = Fixes::new();
+    if !config.rules().rounding.is_empty() {
+        fixes = fixes.with(RoundingFix::new(Roundings::new(
+            &config.rules().rounding,
+            projects,
+        )));
+    }
+    if config.rules().breaks.enabled {
+        fixes = fixes.with(
+            BreakFix::new(config.rules().breaks.clone())
+                .with_roundings(Roundings::new(&config.rules().rounding, projects)),
+        );
+    }
+    fixes
+}
+
+fn holidays(config: &Config) -> HolidayCalendar {
+    let mut holidays = HolidayCalendar::new();
+    if let Some(state) = &config.holidays().state {
+        holidays = holidays.with_state(state.parse().unwrap_or_else(|err| {
+            eprintln!("Holidays: {}", err);
+            process::exit(1);
+        }));
+    }
+    for file in &config.holidays().files {
+        let content = std::fs::read_to_string(file).unwrap_or_else(|err| {
+            eprintln!("Holidays {}: {}", file.display(), err);
+            process::exit(1);
+        });
+        let (events, errors) = HolidayCalendar::parse(&content, &file.to_string_lossy())
+            .unwrap_or_else(|err| {
+                eprintln!("Problem parsing holidays file {}: {}", file.display(), err);
+                process::exit(1);
+            });
+        for error in errors {
+            eprintln!("Holidays {}: {}", file.display(), error);
+        }
+        holidays = holidays.with_events(events);
+    }
+    holidays
+}
+
+fn jira_issues(file: &Path) -> Issues {
     let content = std::fs::read_to_string(file).unwrap_or_else(|err| {
-        eprintln!("Projects: {}", err);
+        eprintln!("Jira issues {}: {}", file.display(), err);
         process::exit(1);
     });
+    Issues::parse(&content, &file.to_string_lossy()).unwrap_or_else(|err| {
+        eprintln!(
+            "Problem parsing Jira issues file {}: {}",
+            file.display(),
+            err
+        );
+        process::exit(1);
+    })
+}
+
+fn harvest_projects(matches: &ArgMatches, config: &Config) -> Projects {
+    let harvest = self::harvest_config(config);
+    let mode = if matches.is_present("offline") {
+        CacheMode::Offline
+    } else if matches.is_present("refresh-projects") {
+        CacheMode::Refresh
+    } else {
+        CacheMode::UseFresh
+    };
+    let cache = harvest
+        .account_id
+        .as_ref()
+        .and_then(|account_id| {
+            ProjectsCache::in_cache_directory(
+                account_id,
+                harvest.base_url.as_deref(),
+                Duration::hours(harvest.cache_ttl_hours.unwrap_or(24)),
+            )
+        })
+        .unwrap_or_else(|| {
+            eprintln!("Projects: {}", ApiError::MissingSetting("account_id"));
+            process::exit(1);
+        });
+
+    let (assignments, origin) = cache
+        .load(mode, Utc::now(), || {
+            Client::new(&harvest).and_then(|client| client.project_assignments())
+        })
+        .unwrap_or_else(|err| {
+            eprintln!("Projects: {}", err);
+            process::exit(1);
+        });
+    match origin {
+        Origin::CacheAfterFailure(fetched_at, err) => eprintln!(
+            "Warning: {}, using project assignments cached at {} in {}",
+            err,
+            fetched_at.with_timezone(&Local).format("%Y-%m-%d %H:%M"),
+            cache.file().display()
+        ),
+        Origin::ApiWithoutCache(err) => eprintln!(
+            "Warning: Could not cache project assignments in {}: {}",
+            cache.file().display(),
+            err
+        ),
+        Origin::Api | Origin::Cache(_) => {}
+    }
+    assignments.to_projects()
+}
+
+fn harvest_config(config: &Config) -> HarvestConfig {
+    config
+        .harvest()
+        .clone()
+        .with_environment(|name| std::env::var(name).ok())
+}
+
+fn project_file(file: &Path) -> Projects {
+    let (projects, errors) = self::parse_projects_file(file);
+    for error in errors {
+        eprintln!("Warning {}: {}", file.display(), error);
+    }
+    projects
+}
 
-    timing::parse_projects(&content).unwrap_or_else(|err| {
-        eprintln!("Problem parsing projects file: {}", err);
+fn parse_projects_file(file: &Path) -> (Projects, Vec<LintError>) {
+    let content = std::fs::read_to_string(file).unwrap_or_else(|err| {
+        eprintln!("Projects {}: {}", file.display(), err);
+        process::exit(1);
+    });
+    timing::check_projects(&content, &file.to_string_lossy()).unwrap_or_else(|err| {
+        eprintln!("Problem parsing projects file {}: {}", file.display(), err);
         process::exit(1);
     })
 }
@@ -1,48 +1,31 @@
-use crate::parser::{parse_error::ParseError, time};
+use crate::parser::{fields::Fields, parse_error::ParseError};
 use crate::projects_and_tasks::projects::Projects;
-use crate::validation::activity::{Activity, Description};
+use crate::validation::activity::Activity;
 
 pub struct ActivityLine {
     line: String,
+    description_takes_rest: bool,
 }
 
 impl ActivityLine {
     pub fn new(line: &str) -> Self {
         ActivityLine {
             line: line.to_string(),
+            description_takes_rest: false,
         }
     }
 
+    pub fn with_description_taking_rest(mut self, description_takes_rest: bool) -> Self {
+        self.description_takes_rest = description_takes_rest;
+        self
+    }
+
     pub fn parse(&self, projects: &Projects) -> Result<Activity, ParseError> {
-        let parts = Self::split(&self.line)?;
-
-        let start = time::start(parts.get(1).unwrap());
-        let end = time::end(parts.get(2).unwrap());
-        let project_and_task =
-            projects.get_project_with_task(parts.get(3).unwrap(), parts.get(4).unwrap());
-        let description = Description::new(parts.get(5).unwrap().to_string());
-
-        ParseError::from_arguments(&start, &end, &project_and_task)?;
-
-        let (project, task) = project_and_task.unwrap();
-        Ok(Activity::from(
-            start.unwrap(),
-            end.unwrap(),
-            project,
-            task,
-            description,
-        ))
+        self.fields()?.parse(projects)
     }
 
-    fn split(line: &str) -> Result<Vec<&str>, ParseError> {
-        let parts = line
-            .split('|')
-            .map(|part| part.trim())
-            .collect::<Vec<&str>>();
-        if parts.len() < 7 {
-            return Err(ParseError::TooFewArguments);
-        }
-        Ok(parts)
+    pub fn fields(&self) -> Result<Fields, ParseError> {
+        Fields::from_row(&self.line, self.description_takes_rest)
     }
 }
 
@@ -56,7 +39,10 @@ mod tests {
         task::{Task, TaskBuilder},
         tasks::TasksBuilder,
     };
-    use crate::validation::time::{End, Start};
+    use crate::validation::{
+        activity::Description,
+        time::{End, Start},
+    };
     use chrono::prelude::*;
 
     fn task() -> Task {
@@ -103,6 +89,46 @@ mod tests {
         assert_eq!(activity, Err(ParseError::TooFewArguments))
     }
 
+    #[test]
+    fn it_throws_when_unexpected_columns_are_given() {
+        let line = ActivityLine::new(
+            " | 2020-01-12T08:00:00 | 2020-01-12T08:30:00 | Project | Task | Fix a || b condition | ",
+        );
+
+        let activity = line.parse(&projects());
+
+        assert_eq!(activity, Err(ParseError::TooManyArguments))
+    }
+
+    #[test]
+    fn it_keeps_escaped_pipes_in_the_description() {
+        let line = ActivityLine::new(
+            r" | 2020-01-12T08:00:00 | 2020-01-12T08:30:00 | Project | Task | Fix a \|\| b condition | ",
+        );
+
+        let fields = line.fields();
+
+        assert_eq!(
+            fields.map(|fields| fields.description),
+            Ok("Fix a || b condition".to_string())
+        )
+    }
+
+    #[test]
+    fn it_takes_everything_after_the_task_as_description_when_configured() {
+        let line = ActivityLine::new(
+            " | 2020-01-12T08:00:00 | 2020-01-12T08:30:00 | Project | Task | Fix a || b condition | ",
+        )
+        .with_description_taking_rest(true);
+
+        let fields = line.fields();
+
+        assert_eq!(
+            fields.map(|fields| fields.description),
+            Ok("Fix a || b condition".to_string())
+        )
+    }
+
     #[test]
     fn it_throws_when_start_and_end_cannot_be_converted_to_date_time() {
         let line = ActivityLine::new(
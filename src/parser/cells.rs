@@ -0,0 +1,92 @@
+use std::ops::Range;
+
+pub fn split(row: &str, limit: Option<usize>) -> Vec<String> {
+    let mut cells = vec![];
+    let mut cell = String::new();
+    let mut characters = row.chars().peekable();
+    while let Some(character) = characters.next() {
+        match character {
+            '\\' if characters.peek() == Some(&'|') => cell.push(characters.next().unwrap()),
+            '|' if limit.is_none_or(|limit| cells.len() + 1 < limit) => {
+                cells.push(std::mem::take(&mut cell))
+            }
+            _ => cell.push(character),
+        }
+    }
+    cells.push(cell);
+    cells.iter().map(|cell| cell.trim().to_string()).collect()
+}
+
+pub fn ranges(row: &str) -> Vec<Range<usize>> {
+    let stripped = strip_outer_pipes(row);
+    let offset = stripped.as_ptr() as usize - row.as_ptr() as usize;
+    let mut ranges = vec![];
+    let mut start = 0;
+    let mut escaped = false;
+    for (index, character) in stripped.char_indices() {
+        match character {
+            '|' if !escaped => {
+                ranges.push(trimmed(stripped, start..index, offset));
+                start = index + 1;
+            }
+            _ => escaped = character == '\\',
+        }
+    }
+    ranges.push(trimmed(stripped, start..stripped.len(), offset));
+    ranges
+}
+
+pub fn trimmed(row: &str, range: Range<usize>, offset: usize) -> Range<usize> {
+    let cell = &row[range.clone()];
+    let start = range.start + cell.len() - cell.trim_start().len();
+    let end = range.end - (cell.len() - cell.trim_end().len());
+    offset + start..offset + end.max(start)
+}
+
+pub fn strip_outer_pipes(row: &str) -> &str {
+    let row = row.trim();
+    let row = row.strip_prefix('|').unwrap_or(row);
+    match row.strip_suffix('|') {
+        Some(stripped) if !stripped.ends_with('\\') => stripped,
+        _ => row,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_splits_on_unescaped_pipes_only() {
+        assert_eq!(
+            split(r" a | Fix a \|\| b | c ", None),
+            vec!["a", "Fix a || b", "c"]
+        );
+    }
+
+    #[test]
+    fn it_keeps_the_rest_in_the_last_cell_when_limited() {
+        assert_eq!(
+            split(r" a | Fix a || b \| c ", Some(2)),
+            vec!["a", "Fix a || b | c"]
+        );
+    }
+
+    #[test]
+    fn it_finds_trimmed_cells_of_a_row() {
+        let row = r"  | a |  Fix \| b | c |";
+
+        let cells = ranges(row)
+            .into_iter()
+            .map(|range| &row[range])
+            .collect::<Vec<_>>();
+
+        assert_eq!(cells, vec!["a", r"Fix \| b", "c"]);
+    }
+
+    #[test]
+    fn it_keeps_escaped_pipes_at_the_end_of_a_row() {
+        assert_eq!(strip_outer_pipes(r" | a | b \| "), r" a | b \|");
+        assert_eq!(strip_outer_pipes(" | a | b | "), " a | b ");
+    }
+}
@@ -0,0 +1,230 @@
+use crate::config::CsvColumns;
+use crate::parser::{cells, fields::Fields, parse_error::ParseError};
+use csv::{Position, StringRecord};
+use std::ops::Range;
+
+pub struct CsvSheet<'a> {
+    text: &'a str,
+    delimiter: u8,
+    columns: &'a CsvColumns,
+}
+
+struct ColumnIndices {
+    start: usize,
+    end: usize,
+    project: usize,
+    task: usize,
+    description: usize,
+}
+
+impl<'a> CsvSheet<'a> {
+    pub fn new(text: &'a str, delimiter: u8, columns: &'a CsvColumns) -> Self {
+        Self {
+            text,
+            delimiter,
+            columns,
+        }
+    }
+
+    pub fn records(&self) -> Vec<(usize, Result<Fields, ParseError>)> {
+        let mut reader = csv::ReaderBuilder::new()
+            .delimiter(self.delimiter)
+            .flexible(true)
+            .from_reader(self.text.as_bytes());
+        let indices = match reader
+            .headers()
+            .map_err(|error| ParseError::InvalidRecord(error.to_string()))
+            .and_then(|headers| self.indices(headers))
+        {
+            Ok(indices) => indices,
+            Err(error) => return vec![(0, Err(error))],
+        };
+
+        reader
+            .records()
+            .map(|record| match record {
+                Ok(record) => (self.line(record.position()), indices.fields(&record)),
+                Err(error) => (
+                    self.line(error.position()),
+                    Err(ParseError::InvalidRecord(error.to_string())),
+                ),
+            })
+            .collect()
+    }
+
+    pub fn column_positions(&self) -> Option<Vec<usize>> {
+        let mut reader = csv::ReaderBuilder::new()
+            .delimiter(self.delimiter)
+            .from_reader(self.text.as_bytes());
+        let indices = self.indices(reader.headers().ok()?).ok()?;
+        Some(vec![
+            indices.start,
+            indices.end,
+            indices.project,
+            indices.task,
+            indices.description,
+        ])
+    }
+
+    pub fn ranges(&self, line: &str) -> Vec<Range<usize>> {
+        let delimiter = self.delimiter as char;
+        let mut ranges = vec![];
+        let mut start = 0;
+        let mut quoted = false;
+        for (index, character) in line.char_indices() {
+            match character {
+                '"' => quoted = !quoted,
+                _ if character == delimiter && !quoted => {
+                    ranges.push(cells::trimmed(line, start..index, 0));
+                    start = index + 1;
+                }
+                _ => {}
+            }
+        }
+        ranges.push(cells::trimmed(line, start..line.len(), 0));
+        ranges
+    }
+
+    fn line(&self, position: Option<&Position>) -> usize {
+        let bytes = self.text.as_bytes();
+        let start = position.map_or(0, |position| position.byte() as usize);
+        let skipped_empty_lines = bytes[start..]
+            .iter()
+            .take_while(|byte| **byte == b'\n' || **byte == b'\r')
+            .count();
+        bytes[..start + skipped_empty_lines]
+            .iter()
+            .filter(|byte| **byte == b'\n')
+            .count()
+    }
+
+    fn indices(&self, headers: &StringRecord) -> Result<ColumnIndices, ParseError> {
+        let index = |name: &str| {
+            headers
+                .iter()
+                .position(|header| header.trim().eq_ignore_ascii_case(name.trim()))
+                .ok_or_else(|| ParseError::MissingColumn(name.to_string()))
+        };
+        Ok(ColumnIndices {
+            start: index(&self.columns.start)?,
+            end: index(&self.columns.end)?,
+            project: index(&self.columns.project)?,
+            task: index(&self.columns.task)?,
+            description: index(&self.columns.description)?,
+        })
+    }
+}
+
+impl ColumnIndices {
+    fn fields(&self, record: &StringRecord) -> Result<Fields, ParseError> {
+        let cell = |index: usize| {
+            record
+                .get(index)
+                .map(|cell| cell.trim().to_string())
+                .ok_or(ParseError::TooFewArguments)
+        };
+        Ok(Fields {
+            start: cell(self.start)?,
+            end: cell(self.end)?,
+            project: cell(self.project)?,
+            task: cell(self.task)?,
+            description: cell(self.description)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fields(description: &str) -> Fields {
+        Fields {
+            start: "2020-01-12T08:00:00".to_string(),
+            end: "2020-01-12T08:30:00".to_string(),
+            project: "Project".to_string(),
+            task: "Task".to_string(),
+            description: description.to_string(),
+        }
+    }
+
+    #[test]
+    fn it_reads_columns_by_header_name() {
+        let text = r#"Description,Project,Task,Start,End
+"Fix a | b, c",Project,Task,2020-01-12T08:00:00,2020-01-12T08:30:00
+
+Improve Readme,Project,Task,2020-01-12T08:00:00,2020-01-12T08:30:00
+"#;
+
+        let records = CsvSheet::new(text, b',', &CsvColumns::default()).records();
+
+        assert_eq!(
+            records,
+            vec![
+                (1, Ok(fields("Fix a | b, c"))),
+                (3, Ok(fields("Improve Readme")))
+            ]
+        );
+    }
+
+    #[test]
+    fn it_reads_tab_separated_values_with_custom_headers() {
+        let text = "Von\tBis\tProjekt\tAufgabe\tBeschreibung\n\
+                    2020-01-12T08:00:00\t2020-01-12T08:30:00\tProject\tTask\tText\n";
+        let columns = CsvColumns {
+            start: "Von".to_string(),
+            end: "Bis".to_string(),
+            project: "Projekt".to_string(),
+            task: "Aufgabe".to_string(),
+            description: "Beschreibung".to_string(),
+        };
+
+        let records = CsvSheet::new(text, b'\t', &columns).records();
+
+        assert_eq!(records, vec![(1, Ok(fields("Text")))]);
+    }
+
+    #[test]
+    fn it_finds_cells_of_the_configured_columns() {
+        let text = "Description,Start,End,Project,Task\n";
+        let columns = CsvColumns::default();
+        let sheet = CsvSheet::new(text, b',', &columns);
+        let line = r#""Fix a, ""b""" , 2020-01-12T08:00:00,2020-01-12T08:30:00,P,T"#;
+
+        let cells = sheet
+            .ranges(line)
+            .into_iter()
+            .map(|range| &line[range])
+            .collect::<Vec<_>>();
+
+        assert_eq!(sheet.column_positions(), Some(vec![1, 2, 3, 4, 0]));
+        assert_eq!(
+            cells,
+            vec![
+                r#""Fix a, ""b""""#,
+                "2020-01-12T08:00:00",
+                "2020-01-12T08:30:00",
+                "P",
+                "T"
+            ]
+        );
+    }
+
+    #[test]
+    fn it_reports_missing_columns_at_the_header() {
+        let records = CsvSheet::new("Start,End\n", b',', &CsvColumns::default()).records();
+
+        assert_eq!(
+            records,
+            vec![(0, Err(ParseError::MissingColumn("Project".to_string())))]
+        );
+    }
+
+    #[test]
+    fn it_reports_rows_with_too_few_cells() {
+        let text = "Start,End,Project,Task,Description\n2020-01-12T08:00:00,2020\n";
+
+        let records = CsvSheet::new(text, b',', &CsvColumns::default()).records();
+
+        assert_eq!(records, vec![(1, Err(ParseError::TooFewArguments))]);
+    }
+}
@@ -0,0 +1,100 @@
+use crate::parser::{cells, parse_error::ParseError, time};
+use crate::projects_and_tasks::projects::Projects;
+use crate::validation::activity::{Activity, Description};
+use std::fmt;
+
+#[derive(Debug, PartialEq)]
+pub struct Fields {
+    pub start: String,
+    pub end: String,
+    pub project: String,
+    pub task: String,
+    pub description: String,
+}
+
+impl Fields {
+    pub fn from_row(row: &str, description_takes_rest: bool) -> Result<Self, ParseError> {
+        let limit = if description_takes_rest {
+            Some(5)
+        } else {
+            None
+        };
+        let cells = cells::split(cells::strip_outer_pipes(row), limit);
+        if cells.len() < 5 {
+            return Err(ParseError::TooFewArguments);
+        }
+        if cells.len() > 5 {
+            return Err(ParseError::TooManyArguments);
+        }
+        let mut cells = cells.into_iter();
+        let mut cell = || cells.next().unwrap();
+        Ok(Self {
+            start: cell(),
+            end: cell(),
+            project: cell(),
+            task: cell(),
+            description: cell(),
+        })
+    }
+
+    pub fn parse(&self, projects: &Projects) -> Result<Activity, ParseError> {
+        let start = time::start(&self.start);
+        let end = time::end(&self.end);
+        let project_and_task = projects.get_project_with_task(&self.project, &self.task);
+        let description = Description::new(self.description.clone());
+
+        ParseError::from_arguments(&start, &end, &project_and_task)?;
+
+        let (project, task) = project_and_task.unwrap();
+        Ok(Activity::from(
+            start.unwrap(),
+            end.unwrap(),
+            project,
+            task,
+            description,
+        ))
+    }
+}
+
+impl fmt::Display for Fields {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let cells = [
+            &self.start,
+            &self.end,
+            &self.project,
+            &self.task,
+            &self.description,
+        ];
+        write!(
+            f,
+            "| {} |",
+            cells
+                .iter()
+                .map(|cell| cell.replace('|', "\\|"))
+                .collect::<Vec<_>>()
+                .join(" | ")
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_writes_an_activity_line_with_escaped_pipes() {
+        let fields = Fields {
+            start: "2020-01-12T08:00:00".to_string(),
+            end: "2020-01-12T08:30:00".to_string(),
+            project: "Project".to_string(),
+            task: "Task".to_string(),
+            description: "Fix a || b".to_string(),
+        };
+
+        assert_eq!(
+            fields.to_string(),
+            r"| 2020-01-12T08:00:00 | 2020-01-12T08:30:00 | Project | Task | Fix a \|\| b |"
+        );
+        assert_eq!(Fields::from_row(&fields.to_string(), false), Ok(fields));
+    }
+}
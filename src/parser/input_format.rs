@@ -0,0 +1,165 @@
+use crate::config::{Config, CsvColumns, TableConfig};
+use crate::parser::cells;
+use crate::parser::{
+    activity_line::ActivityLine, csv_sheet::CsvSheet, fields::Fields,
+    markdown_tables::MarkdownTables, parse_error::ParseError, timeclock::Timeclock,
+    timewarrior::Timewarrior,
+};
+use crate::projects_and_tasks::projects::Projects;
+use std::ops::Range;
+use std::path::Path;
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum InputFormat {
+    Org(TableConfig),
+    Markdown(TableConfig),
+    Csv { delimiter: u8, columns: CsvColumns },
+    Timewarrior,
+    Timeclock,
+}
+
+impl InputFormat {
+    pub fn detect(file_name: &str, name: Option<&str>, config: &Config) -> Self {
+        let extension = Path::new(file_name)
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .map(str::to_lowercase);
+        let csv_format = |delimiter: u8| Self::Csv {
+            delimiter: config.csv().delimiter.unwrap_or(delimiter),
+            columns: config.csv().columns.clone(),
+        };
+        match name.or(extension.as_deref()) {
+            Some("csv") => csv_format(b','),
+            Some("tsv") | Some("tab") => csv_format(b'\t'),
+            Some("markdown") | Some("md") => Self::Markdown(config.table().clone()),
+            Some("timewarrior") | Some("data") => Self::Timewarrior,
+            Some("timeclock") => Self::Timeclock,
+            _ => Self::Org(config.table().clone()),
+        }
+    }
+
+    pub fn is_editable(&self) -> bool {
+        matches!(self, Self::Org(_) | Self::Markdown(_) | Self::Csv { .. })
+    }
+
+    pub fn escape(&self, cell: &str) -> String {
+        match self {
+            Self::Csv { delimiter, .. } if cell.contains([*delimiter as char, '"', '\n']) => {
+                format!("\"{}\"", cell.replace('"', "\"\""))
+            }
+            Self::Org(_) | Self::Markdown(_) => cell.replace('|', "\\|"),
+            _ => cell.to_string(),
+        }
+    }
+
+    pub fn unescape(&self, cell: &str) -> String {
+        match self {
+            Self::Csv { .. } => match cell
+                .strip_prefix('"')
+                .and_then(|cell| cell.strip_suffix('"'))
+            {
+                Some(quoted) => quoted.replace("\"\"", "\""),
+                None => cell.to_string(),
+            },
+            Self::Org(_) | Self::Markdown(_) => cell.replace("\\|", "|"),
+            _ => cell.to_string(),
+        }
+    }
+
+    pub fn column_positions(&self, text: &str) -> Vec<usize> {
+        match self {
+            Self::Org(_) | Self::Markdown(_) => (0..5).collect(),
+            Self::Csv { delimiter, columns } => CsvSheet::new(text, *delimiter, columns)
+                .column_positions()
+                .unwrap_or_default(),
+            _ => vec![],
+        }
+    }
+
+    pub fn cell_ranges(&self, line: &str) -> Vec<Range<usize>> {
+        match self {
+            Self::Org(_) | Self::Markdown(_) => cells::ranges(line),
+            Self::Csv { delimiter, columns } => CsvSheet::new("", *delimiter, columns).ranges(line),
+            _ => vec![],
+        }
+    }
+
+    pub fn records(
+        &self,
+        text: &str,
+        projects: &Projects,
+    ) -> Vec<(usize, Result<Fields, ParseError>)> {
+        match self {
+            Self::Org(table) => text
+                .lines()
+                .enumerate()
+                .filter(|(_no, line)| !line.is_empty())
+                .map(|(no, line)| {
+                    let activity_line = ActivityLine::new(line)
+                        .with_description_taking_rest(table.description_takes_rest);
+                    (no, activity_line.fields())
+                })
+                .collect(),
+            Self::Markdown(table) => {
+                MarkdownTables::new(text, table.description_takes_rest).records()
+            }
+            Self::Csv { delimiter, columns } => CsvSheet::new(text, *delimiter, columns).records(),
+            Self::Timewarrior => Timewarrior::new(text, projects).records(),
+            Self::Timeclock => Timeclock::new(text).records(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_detects_format_by_extension() {
+        let config = Config::default();
+
+        assert_eq!(
+            InputFormat::detect("timesheet.org", None, &config),
+            InputFormat::Org(TableConfig::default())
+        );
+        assert_eq!(
+            InputFormat::detect("timesheet.md", None, &config),
+            InputFormat::Markdown(TableConfig::default())
+        );
+        assert_eq!(
+            InputFormat::detect("2020-01.data", None, &config),
+            InputFormat::Timewarrior
+        );
+        assert_eq!(
+            InputFormat::detect("work.timeclock", None, &config),
+            InputFormat::Timeclock
+        );
+        assert_eq!(
+            InputFormat::detect("timesheet.CSV", None, &config),
+            InputFormat::Csv {
+                delimiter: b',',
+                columns: CsvColumns::default()
+            }
+        );
+        assert_eq!(
+            InputFormat::detect("timesheet.tsv", None, &config),
+            InputFormat::Csv {
+                delimiter: b'\t',
+                columns: CsvColumns::default()
+            }
+        );
+    }
+
+    #[test]
+    fn it_prefers_given_format_and_configured_delimiter() {
+        let config = Config::from("[csv]\ndelimiter = \";\"\n").unwrap();
+
+        assert_eq!(
+            InputFormat::detect("timesheet.txt", Some("csv"), &config),
+            InputFormat::Csv {
+                delimiter: b';',
+                columns: CsvColumns::default()
+            }
+        );
+    }
+}
@@ -0,0 +1,93 @@
+use crate::parser::{activity_line::ActivityLine, cells, fields::Fields, parse_error::ParseError};
+
+pub struct MarkdownTables<'a> {
+    text: &'a str,
+    description_takes_rest: bool,
+}
+
+impl<'a> MarkdownTables<'a> {
+    pub fn new(text: &'a str, description_takes_rest: bool) -> Self {
+        Self {
+            text,
+            description_takes_rest,
+        }
+    }
+
+    pub fn records(&self) -> Vec<(usize, Result<Fields, ParseError>)> {
+        let mut records = vec![];
+        let mut in_table = false;
+        for (no, line) in self.text.lines().enumerate() {
+            let row = cells::split(cells::strip_outer_pipes(line), None);
+            if row.len() < 2 {
+                in_table = false;
+            } else if is_alignment_row(&row) {
+                in_table = true;
+            } else if in_table {
+                let activity_line = ActivityLine::new(line)
+                    .with_description_taking_rest(self.description_takes_rest);
+                records.push((no, activity_line.fields()));
+            }
+        }
+        records
+    }
+}
+
+fn is_alignment_row(row: &[String]) -> bool {
+    row.iter().all(|cell| {
+        let dashes = cell.strip_prefix(':').unwrap_or(cell);
+        let dashes = dashes.strip_suffix(':').unwrap_or(dashes);
+        !dashes.is_empty() && dashes.chars().all(|character| character == '-')
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fields(description: &str) -> Fields {
+        Fields {
+            start: "2020-01-12T08:00:00".to_string(),
+            end: "2020-01-12T08:30:00".to_string(),
+            project: "Project".to_string(),
+            task: "Task".to_string(),
+            description: description.to_string(),
+        }
+    }
+
+    #[test]
+    fn it_reads_rows_of_all_tables_between_prose() {
+        let text = r#"# Week 2
+
+Monday was busy | really.
+
+| Start | End | Project | Task | Description |
+|-------|:---:|---------|-----:|:------------|
+| 2020-01-12T08:00:00 | 2020-01-12T08:30:00 | Project | Task | Fix a \|\| b |
+
+Some notes.
+
+Start | End | Project | Task | Description
+--- | --- | --- | --- | ---
+2020-01-12T08:00:00 | 2020-01-12T08:30:00 | Project | Task | Improve Readme
+"#;
+
+        let records = MarkdownTables::new(text, false).records();
+
+        assert_eq!(
+            records,
+            vec![
+                (6, Ok(fields("Fix a || b"))),
+                (12, Ok(fields("Improve Readme")))
+            ]
+        );
+    }
+
+    #[test]
+    fn it_reports_rows_with_too_few_cells() {
+        let text = "| a | b |\n|---|---|\n| 2020-01-12T08:00:00 | Project |\n";
+
+        let records = MarkdownTables::new(text, false).records();
+
+        assert_eq!(records, vec![(2, Err(ParseError::TooFewArguments))]);
+    }
+}
@@ -1,3 +1,10 @@
 pub mod activity_line;
+pub mod cells;
+pub mod csv_sheet;
+pub mod fields;
+pub mod input_format;
+pub mod markdown_tables;
 pub mod parse_error;
 pub mod time;
+pub mod timeclock;
+pub mod timewarrior;
@@ -6,6 +6,9 @@ use std::fmt;
 #[derive(Debug, PartialEq)]
 pub enum ParseError {
     TooFewArguments,
+    TooManyArguments,
+    MissingColumn(String),
+    InvalidRecord(String),
     ArgumentErrors(Vec<ArgumentParseError>),
 }
 
@@ -16,6 +19,19 @@ impl fmt::Display for ParseError {
             Self::TooFewArguments => {
                 write!(f, "{:<10} | {}: Too few arguments given", "All", error_name)
             }
+            Self::TooManyArguments => {
+                write!(
+                    f,
+                    "{:<10} | {}: Too many arguments given",
+                    "All", error_name
+                )
+            }
+            Self::MissingColumn(column) => write!(
+                f,
+                "{:<10} | {}: Column '{}' is missing",
+                "All", error_name, column
+            ),
+            Self::InvalidRecord(error) => write!(f, "{:<10} | {}: {}", "All", error_name, error),
             Self::ArgumentErrors(errors) => write!(
                 f,
                 "{}",
@@ -43,8 +59,7 @@ impl ParseError {
 
         let errors = vec
             .into_iter()
-            .filter(|option| option.is_err())
-            .map(|option| option.unwrap_err())
+            .filter_map(Result::err)
             .collect::<Vec<ArgumentParseError>>();
 
         if !errors.is_empty() {
@@ -56,45 +71,36 @@ impl ParseError {
 
     pub fn at_line(self, line_number: usize) -> Vec<LineError> {
         match &self {
-            Self::TooFewArguments => vec![LineError::new(line_number, self.to_string())],
             Self::ArgumentErrors(errors) => errors
                 .iter()
                 .map(|e| LineError::new(line_number, e.to_string()))
                 .collect::<Vec<_>>(),
+            _ => vec![LineError::new(line_number, self.to_string())],
         }
     }
 
-    fn map_start(
-        start: &Result<Start, DateTimeParseError>,
-    ) -> Result<Argument, ArgumentParseError> {
+    fn map_start(start: &Result<Start, DateTimeParseError>) -> Result<(), ArgumentParseError> {
         start
-            .clone()
-            .map(Argument::Start)
-            .map_err(ArgumentParseError::Start)
+            .as_ref()
+            .map(|_| ())
+            .map_err(|error| ArgumentParseError::Start(error.clone()))
     }
 
-    fn map_end(end: &Result<End, DateTimeParseError>) -> Result<Argument, ArgumentParseError> {
-        end.clone()
-            .map(Argument::End)
-            .map_err(ArgumentParseError::End)
+    fn map_end(end: &Result<End, DateTimeParseError>) -> Result<(), ArgumentParseError> {
+        end.as_ref()
+            .map(|_| ())
+            .map_err(|error| ArgumentParseError::End(error.clone()))
     }
 
     fn map_project_and_tak(
         pt: &Result<(Project, Task), ProjectError>,
-    ) -> Result<Argument, ArgumentParseError> {
-        pt.clone()
-            .map(Argument::ProjectAndTask)
-            .map_err(ArgumentParseError::ProjectAndTask)
+    ) -> Result<(), ArgumentParseError> {
+        pt.as_ref()
+            .map(|_| ())
+            .map_err(|error| ArgumentParseError::ProjectAndTask(error.clone()))
     }
 }
 
-#[derive(Clone, Debug)]
-enum Argument {
-    Start(Start),
-    End(End),
-    ProjectAndTask((Project, Task)),
-}
-
 #[derive(Debug, PartialEq, Clone)]
 pub enum ArgumentParseError {
     Start(DateTimeParseError),
@@ -0,0 +1,179 @@
+use crate::parser::{fields::Fields, parse_error::ParseError};
+use chrono::NaiveDateTime;
+
+const DATE_TIME_FORMATS: [&str; 4] = [
+    "%Y/%m/%d %H:%M:%S",
+    "%Y/%m/%d %H:%M",
+    "%Y-%m-%d %H:%M:%S",
+    "%Y-%m-%d %H:%M",
+];
+
+pub struct Timeclock<'a> {
+    text: &'a str,
+}
+
+struct ClockIn {
+    line_number: usize,
+    start: String,
+    account: String,
+    description: String,
+}
+
+impl<'a> Timeclock<'a> {
+    pub fn new(text: &'a str) -> Self {
+        Self { text }
+    }
+
+    pub fn records(&self) -> Vec<(usize, Result<Fields, ParseError>)> {
+        let mut records = vec![];
+        let mut clock_in: Option<ClockIn> = None;
+        for (no, line) in self.text.lines().enumerate() {
+            let mut words = line.splitn(2, char::is_whitespace);
+            let code = words.next().unwrap_or_default();
+            let rest = words.next().unwrap_or_default().trim_start();
+            match code {
+                "i" | "I" => {
+                    if let Some(open) = clock_in.take() {
+                        records.push((open.line_number, Err(not_clocked_out())));
+                    }
+                    match ClockIn::parse(no, rest) {
+                        Ok(entry) => clock_in = Some(entry),
+                        Err(error) => records.push((no, Err(error))),
+                    }
+                }
+                "o" | "O" => match clock_in.take() {
+                    Some(open) => records.push((open.line_number, open.clock_out(rest))),
+                    None => records.push((
+                        no,
+                        Err(ParseError::InvalidRecord(
+                            "Clock-out without clock-in".to_string(),
+                        )),
+                    )),
+                },
+                _ => {}
+            }
+        }
+        if let Some(open) = clock_in {
+            records.push((open.line_number, Err(not_clocked_out())));
+        }
+        records
+    }
+}
+
+impl ClockIn {
+    fn parse(line_number: usize, rest: &str) -> Result<Self, ParseError> {
+        let (start, rest) = date_time(rest)?;
+        let (account, description) = match rest.find("  ").or_else(|| rest.find('\t')) {
+            Some(index) => (&rest[..index], rest[index..].trim()),
+            None => (rest, ""),
+        };
+        Ok(Self {
+            line_number,
+            start,
+            account: account.trim().to_string(),
+            description: description.to_string(),
+        })
+    }
+
+    fn clock_out(self, rest: &str) -> Result<Fields, ParseError> {
+        let (end, _comment) = date_time(rest)?;
+        let mut segments = self.account.rsplitn(3, ':');
+        let task = segments.next().unwrap_or_default();
+        let project = segments.next().ok_or(ParseError::TooFewArguments)?;
+        Ok(Fields {
+            start: self.start,
+            end,
+            project: project.to_string(),
+            task: task.to_string(),
+            description: self.description,
+        })
+    }
+}
+
+fn date_time(text: &str) -> Result<(String, &str), ParseError> {
+    let mut words = text.trim_start().splitn(3, char::is_whitespace);
+    let candidate = format!(
+        "{} {}",
+        words.next().unwrap_or_default(),
+        words.next().unwrap_or_default()
+    );
+    let time = DATE_TIME_FORMATS
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(&candidate, format).ok())
+        .ok_or_else(|| ParseError::InvalidRecord(format!("Invalid time '{}'", candidate.trim())))?;
+    Ok((
+        time.format("%Y-%m-%dT%H:%M:%S").to_string(),
+        words.next().unwrap_or_default(),
+    ))
+}
+
+fn not_clocked_out() -> ParseError {
+    ParseError::InvalidRecord("Clock-in without clock-out".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_reads_accounts_as_project_and_task() {
+        let text = "; week 2
+i 2020/01/12 08:00:00 clients:Timeval:Build  Improve Readme
+o 2020/01/12 08:30:00
+
+i 2020-01-12 08:30 Training:Self-Study
+o 2020-01-12 09:00
+";
+
+        let records = Timeclock::new(text).records();
+
+        assert_eq!(
+            records,
+            vec![
+                (
+                    1,
+                    Ok(Fields {
+                        start: "2020-01-12T08:00:00".to_string(),
+                        end: "2020-01-12T08:30:00".to_string(),
+                        project: "Timeval".to_string(),
+                        task: "Build".to_string(),
+                        description: "Improve Readme".to_string(),
+                    })
+                ),
+                (
+                    4,
+                    Ok(Fields {
+                        start: "2020-01-12T08:30:00".to_string(),
+                        end: "2020-01-12T09:00:00".to_string(),
+                        project: "Training".to_string(),
+                        task: "Self-Study".to_string(),
+                        description: "".to_string(),
+                    })
+                )
+            ]
+        );
+    }
+
+    #[test]
+    fn it_reports_unmatched_clock_entries() {
+        let text = "i 2020/01/12 08:00:00 Timeval:Build\ni 2020/01/12 09:00:00 Timeval:Build\no 2020/01/12 10:00:00\no 2020/01/12 11:00:00\n";
+
+        let records = Timeclock::new(text).records();
+
+        assert_eq!(
+            records.iter().map(|(no, _)| *no).collect::<Vec<_>>(),
+            vec![0, 1, 3]
+        );
+        assert_eq!(records[0].1, Err(not_clocked_out()));
+        assert!(records[1].1.is_ok());
+    }
+
+    #[test]
+    fn it_needs_project_and_task_in_the_account() {
+        let text = "i 2020/01/12 08:00:00 Timeval\no 2020/01/12 08:30:00\n";
+
+        let records = Timeclock::new(text).records();
+
+        assert_eq!(records, vec![(0, Err(ParseError::TooFewArguments))]);
+    }
+}
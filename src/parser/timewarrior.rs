@@ -0,0 +1,243 @@
+use crate::parser::{fields::Fields, parse_error::ParseError};
+use crate::projects_and_tasks::projects::Projects;
+use chrono::{Local, TimeZone, Utc};
+
+const DATE_TIME_FORMAT: &str = "%Y%m%dT%H%M%SZ";
+
+pub struct Timewarrior<'a> {
+    text: &'a str,
+    projects: &'a Projects,
+}
+
+impl<'a> Timewarrior<'a> {
+    pub fn new(text: &'a str, projects: &'a Projects) -> Self {
+        Self { text, projects }
+    }
+
+    pub fn records(&self) -> Vec<(usize, Result<Fields, ParseError>)> {
+        self.text
+            .lines()
+            .enumerate()
+            .filter(|(_no, line)| !line.trim().is_empty())
+            .map(|(no, line)| (no, interval(line.trim(), self.projects)))
+            .collect()
+    }
+}
+
+fn interval(line: &str, projects: &Projects) -> Result<Fields, ParseError> {
+    let line = line
+        .strip_prefix("inc ")
+        .ok_or_else(|| ParseError::InvalidRecord("Line is no Timewarrior interval".to_string()))?;
+    let (times, tags) = line.split_once(" #").unwrap_or((line, ""));
+    let (start, end) = times
+        .split_once(" - ")
+        .ok_or_else(|| ParseError::InvalidRecord("Interval is still open".to_string()))?;
+
+    let words = words(tags);
+    let mut parts = words.split(|word| word == "#");
+    let tags = parts.next().unwrap_or_default();
+    let annotation = parts.next().unwrap_or_default();
+    if tags.len() < 2 {
+        return Err(ParseError::TooFewArguments);
+    }
+    let (project, task) = project_and_task(tags, projects)?;
+    let description = if annotation.is_empty() {
+        tags.iter()
+            .enumerate()
+            .filter(|(i, _tag)| *i != project && *i != task)
+            .map(|(_i, tag)| tag.as_str())
+            .collect::<Vec<_>>()
+            .join(" ")
+    } else {
+        annotation.join(" ")
+    };
+
+    Ok(Fields {
+        start: local(start)?,
+        end: local(end)?,
+        project: tags[project].clone(),
+        task: tags[task].clone(),
+        description,
+    })
+}
+
+fn project_and_task(tags: &[String], projects: &Projects) -> Result<(usize, usize), ParseError> {
+    let pairs = (0..tags.len())
+        .flat_map(|project| (0..tags.len()).map(move |task| (project, task)))
+        .filter(|(project, task)| {
+            project != task
+                && projects
+                    .get_project_with_task(&tags[*project], &tags[*task])
+                    .is_ok()
+        })
+        .collect::<Vec<_>>();
+    match pairs.as_slice() {
+        [] => Ok((0, 1)),
+        [pair] => Ok(*pair),
+        _ => Err(ParseError::InvalidRecord(format!(
+            "Tags name several projects and tasks: {}",
+            pairs
+                .iter()
+                .map(|(project, task)| format!("'{}' '{}'", tags[*project], tags[*task]))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ))),
+    }
+}
+
+fn local(time: &str) -> Result<String, ParseError> {
+    Utc.datetime_from_str(time.trim(), DATE_TIME_FORMAT)
+        .map(|time| {
+            time.with_timezone(&Local)
+                .format("%Y-%m-%dT%H:%M:%S")
+                .to_string()
+        })
+        .map_err(|_| ParseError::InvalidRecord(format!("Invalid time '{}'", time.trim())))
+}
+
+fn words(text: &str) -> Vec<String> {
+    let mut words = vec![];
+    let mut word: Option<String> = None;
+    let mut quoted = false;
+    let mut characters = text.chars();
+    while let Some(character) = characters.next() {
+        match character {
+            '\\' if quoted => {
+                if let Some(escaped) = characters.next() {
+                    word.get_or_insert_with(String::new).push(escaped);
+                }
+            }
+            '"' => {
+                quoted = !quoted;
+                word.get_or_insert_with(String::new);
+            }
+            _ if character.is_whitespace() && !quoted => words.extend(word.take()),
+            _ => word.get_or_insert_with(String::new).push(character),
+        }
+    }
+    words.extend(word);
+    words
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::projects_and_tasks::{
+        project::ProjectWithTasksBuilder, projects::ProjectsBuilder, task::TaskBuilder,
+        tasks::TasksBuilder,
+    };
+
+    fn projects() -> Projects {
+        let project = |name: &str, tasks: &[&str]| {
+            ProjectWithTasksBuilder::new()
+                .with_name(name.to_string())
+                .with_tasks(
+                    TasksBuilder::new()
+                        .with_tasks(
+                            tasks
+                                .iter()
+                                .map(|task| TaskBuilder::new().with_name(task.to_string()).build())
+                                .collect(),
+                        )
+                        .build(),
+                )
+                .build()
+        };
+        ProjectsBuilder::new()
+            .with_projects(vec![
+                project("My Timeval project", &["Build"]),
+                project("Training", &["Self-study", "Build"]),
+            ])
+            .build()
+    }
+
+    fn local_time(time: &str) -> String {
+        local(time).unwrap()
+    }
+
+    #[test]
+    fn it_reads_tags_as_project_task_and_description() {
+        let text = r#"inc 20200112T070000Z - 20200112T073000Z # Timeval Build "Improve Readme"
+
+inc 20200112T073000Z - 20200112T080000Z # "My Timeval project" Build # "Fix \"a\" bug"
+"#;
+
+        let records = Timewarrior::new(text, &projects()).records();
+
+        assert_eq!(
+            records,
+            vec![
+                (
+                    0,
+                    Ok(Fields {
+                        start: local_time("20200112T070000Z"),
+                        end: local_time("20200112T073000Z"),
+                        project: "Timeval".to_string(),
+                        task: "Build".to_string(),
+                        description: "Improve Readme".to_string(),
+                    })
+                ),
+                (
+                    2,
+                    Ok(Fields {
+                        start: local_time("20200112T073000Z"),
+                        end: local_time("20200112T080000Z"),
+                        project: "My Timeval project".to_string(),
+                        task: "Build".to_string(),
+                        description: r#"Fix "a" bug"#.to_string(),
+                    })
+                )
+            ]
+        );
+    }
+
+    #[test]
+    fn it_reports_open_intervals_and_missing_tags() {
+        let text = "inc 20200112T070000Z # Timeval Build\ninc 20200112T070000Z - 20200112T073000Z # Timeval\n";
+
+        let records = Timewarrior::new(text, &projects()).records();
+
+        assert_eq!(
+            records,
+            vec![
+                (
+                    0,
+                    Err(ParseError::InvalidRecord(
+                        "Interval is still open".to_string()
+                    ))
+                ),
+                (1, Err(ParseError::TooFewArguments))
+            ]
+        );
+    }
+
+    #[test]
+    fn it_finds_project_and_task_among_sorted_tags() {
+        let text = "inc 20200112T070000Z - 20200112T073000Z # Build Readme Timeval\ninc 20200112T073000Z - 20200112T080000Z # Build Timeval Training\n";
+
+        let records = Timewarrior::new(text, &projects()).records();
+
+        assert_eq!(
+            records,
+            vec![
+                (
+                    0,
+                    Ok(Fields {
+                        start: local_time("20200112T070000Z"),
+                        end: local_time("20200112T073000Z"),
+                        project: "Timeval".to_string(),
+                        task: "Build".to_string(),
+                        description: "Readme".to_string(),
+                    })
+                ),
+                (
+                    1,
+                    Err(ParseError::InvalidRecord(
+                        "Tags name several projects and tasks: 'Timeval' 'Build', 'Training' 'Build'"
+                            .to_string()
+                    ))
+                )
+            ]
+        );
+    }
+}
@@ -0,0 +1,67 @@
+use crate::projects_and_tasks::clockify::task::ClockifyTask;
+use crate::projects_and_tasks::{
+    project::{ProjectWithTasks, ProjectWithTasksBuilder},
+    task::Task,
+    tasks::TasksBuilder,
+};
+use serde::Deserialize;
+
+#[derive(Deserialize, Debug, PartialEq)]
+pub struct ClockifyProject {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub tasks: Vec<ClockifyTask>,
+}
+
+impl ClockifyProject {
+    pub fn into_project(self) -> ProjectWithTasks {
+        let tasks: Vec<Task> = self
+            .tasks
+            .into_iter()
+            .map(|clockify_task| clockify_task.into_task())
+            .collect();
+
+        ProjectWithTasksBuilder::new()
+            .with_optional_id(None)
+            .with_name(self.name)
+            .with_tasks(TasksBuilder::new().with_tasks(tasks).build())
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::projects_and_tasks::task::TaskBuilder;
+
+    #[test]
+    fn it_creates_project() {
+        let clockify_project = ClockifyProject {
+            id: "5b641568b07987035750505e".to_string(),
+            name: "project".to_string(),
+            tasks: vec![ClockifyTask {
+                id: "5b715448b0798751107918ab".to_string(),
+                name: "task".to_string(),
+            }],
+        };
+
+        let project = clockify_project.into_project();
+
+        assert_eq!(
+            project,
+            ProjectWithTasksBuilder::new()
+                .with_optional_id(None)
+                .with_name("project".to_string())
+                .with_tasks(
+                    TasksBuilder::new()
+                        .with_tasks(vec![TaskBuilder::new()
+                            .with_optional_id(None)
+                            .with_name("task".to_string())
+                            .build()])
+                        .build()
+                )
+                .build()
+        );
+    }
+}
@@ -0,0 +1,111 @@
+use crate::projects_and_tasks::clockify::project::ClockifyProject;
+use crate::projects_and_tasks::{
+    lint::{ProjectOutline, TaskOutline},
+    project::ProjectWithTasks,
+    projects::{Projects, ProjectsBuilder},
+};
+use serde::Deserialize;
+use serde_json::Result;
+
+#[derive(Deserialize, Debug, PartialEq)]
+#[serde(transparent)]
+pub struct ClockifyProjects {
+    projects: Vec<ClockifyProject>,
+}
+
+impl ClockifyProjects {
+    pub fn from(string: &str) -> Result<ClockifyProjects> {
+        serde_json::from_str(string)
+    }
+
+    pub fn to_projects(self) -> Projects {
+        let projects: Vec<ProjectWithTasks> = self
+            .projects
+            .into_iter()
+            .map(|clockify_project| clockify_project.into_project())
+            .collect();
+
+        ProjectsBuilder::new().with_projects(projects).build()
+    }
+
+    pub fn outline(&self) -> Vec<ProjectOutline> {
+        self.projects
+            .iter()
+            .enumerate()
+            .map(|(i, clockify_project)| {
+                let path = format!("$[{}]", i);
+                ProjectOutline {
+                    id: None,
+                    name: clockify_project.name.clone(),
+                    tasks: clockify_project
+                        .tasks
+                        .iter()
+                        .enumerate()
+                        .map(|(j, clockify_task)| TaskOutline {
+                            path: format!("{}.tasks[{}]", path, j),
+                            id: None,
+                            name: clockify_task.name.clone(),
+                        })
+                        .collect(),
+                    path,
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::projects_and_tasks::{
+        project::ProjectWithTasksBuilder, task::TaskBuilder, tasks::TasksBuilder,
+    };
+
+    #[test]
+    fn it_parses_json_into_project_list() {
+        let clockify_projects = ClockifyProjects::from(CLOCKIFY_PROJECTS).unwrap();
+
+        assert_eq!(
+            clockify_projects.to_projects(),
+            ProjectsBuilder::new()
+                .with_projects(vec![ProjectWithTasksBuilder::new()
+                    .with_optional_id(None)
+                    .with_name("Timeval".to_string())
+                    .with_tasks(
+                        TasksBuilder::new()
+                            .with_tasks(vec![TaskBuilder::new()
+                                .with_optional_id(None)
+                                .with_name("Build".to_string())
+                                .build()])
+                            .build()
+                    )
+                    .build()])
+                .build()
+        );
+    }
+
+    const CLOCKIFY_PROJECTS: &str = r#"
+[
+  {
+    "id": "5b641568b07987035750505e",
+    "name": "Timeval",
+    "hourlyRate": { "amount": 0, "currency": "EUR" },
+    "clientId": "",
+    "workspaceId": "5b1667790cb8797321f3d664",
+    "billable": true,
+    "color": "8bc34a",
+    "archived": false,
+    "tasks": [
+      {
+        "id": "5b715448b0798751107918ab",
+        "name": "Build",
+        "projectId": "5b641568b07987035750505e",
+        "assigneeIds": [],
+        "estimate": "PT0S",
+        "status": "ACTIVE"
+      }
+    ]
+  }
+]
+"#;
+}
@@ -0,0 +1,40 @@
+use crate::projects_and_tasks::task::{Task, TaskBuilder};
+use serde::Deserialize;
+
+#[derive(Deserialize, Debug, PartialEq)]
+pub struct ClockifyTask {
+    pub id: String,
+    pub name: String,
+}
+
+impl ClockifyTask {
+    pub fn into_task(self) -> Task {
+        TaskBuilder::new()
+            .with_optional_id(None)
+            .with_name(self.name)
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_creates_task_without_id() {
+        let clockify_task = ClockifyTask {
+            id: "5b715448b0798751107918ab".to_string(),
+            name: "task".to_string(),
+        };
+
+        let task = clockify_task.into_task();
+
+        assert_eq!(
+            task,
+            TaskBuilder::new()
+                .with_optional_id(None)
+                .with_name("task".to_string())
+                .build()
+        );
+    }
+}
@@ -4,9 +4,9 @@ use crate::projects_and_tasks::{
     task::Task,
     tasks::TasksBuilder,
 };
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
-#[derive(Deserialize, Debug, PartialEq)]
+#[derive(Deserialize, Serialize, Debug, PartialEq)]
 pub struct HarvestProject {
     pub project: HarvestProjectIdentification,
     pub task_assignments: Vec<HarvestTask>,
@@ -28,7 +28,7 @@ impl HarvestProject {
     }
 }
 
-#[derive(Deserialize, Debug, PartialEq)]
+#[derive(Deserialize, Serialize, Debug, PartialEq)]
 pub struct HarvestProjectIdentification {
     pub id: u32,
     pub name: String,
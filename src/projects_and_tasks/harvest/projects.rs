@@ -1,12 +1,13 @@
 use crate::projects_and_tasks::harvest::project::HarvestProject;
 use crate::projects_and_tasks::{
+    lint::{ProjectOutline, TaskOutline},
     project::ProjectWithTasks,
     projects::{Projects, ProjectsBuilder},
 };
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use serde_json::Result;
 
-#[derive(Deserialize, Debug, PartialEq)]
+#[derive(Deserialize, Serialize, Debug, PartialEq, Default)]
 pub struct HarvestProjectAssignments {
     project_assignments: Vec<HarvestProject>,
 }
@@ -16,6 +17,10 @@ impl HarvestProjectAssignments {
         serde_json::from_str(string)
     }
 
+    pub fn append(&mut self, other: HarvestProjectAssignments) {
+        self.project_assignments.extend(other.project_assignments);
+    }
+
     pub fn to_projects(self) -> Projects {
         let projects: Vec<ProjectWithTasks> = self
             .project_assignments
@@ -25,6 +30,31 @@ impl HarvestProjectAssignments {
 
         ProjectsBuilder::new().with_projects(projects).build()
     }
+
+    pub fn outline(&self) -> Vec<ProjectOutline> {
+        self.project_assignments
+            .iter()
+            .enumerate()
+            .map(|(i, harvest_project)| {
+                let path = format!("$.project_assignments[{}]", i);
+                ProjectOutline {
+                    path: format!("{}.project", path),
+                    id: Some(harvest_project.project.id),
+                    name: harvest_project.project.name.clone(),
+                    tasks: harvest_project
+                        .task_assignments
+                        .iter()
+                        .enumerate()
+                        .map(|(j, harvest_task)| TaskOutline {
+                            path: format!("{}.task_assignments[{}].task", path, j),
+                            id: Some(harvest_task.task.id),
+                            name: harvest_task.task.name.clone(),
+                        })
+                        .collect(),
+                }
+            })
+            .collect()
+    }
 }
 
 #[cfg(test)]
@@ -60,6 +90,25 @@ mod tests {
         );
     }
 
+    #[test]
+    fn it_outlines_projects_with_json_paths() {
+        let harvest_projects = HarvestProjectAssignments::from(HARVEST_PROJECTS).unwrap();
+
+        assert_eq!(
+            harvest_projects.outline(),
+            vec![ProjectOutline {
+                path: "$.project_assignments[0].project".to_string(),
+                id: Some(95783638),
+                name: "Buddy".to_string(),
+                tasks: vec![TaskOutline {
+                    path: "$.project_assignments[0].task_assignments[0].task".to_string(),
+                    id: Some(42180014),
+                    name: "Buddy (name buddy)".to_string(),
+                }]
+            }]
+        );
+    }
+
     const HARVEST_PROJECTS: &str = r#"
 {
   "project_assignments": [
@@ -1,7 +1,7 @@
 use crate::projects_and_tasks::task::{Task, TaskBuilder};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
-#[derive(Deserialize, Debug, PartialEq)]
+#[derive(Deserialize, Serialize, Debug, PartialEq)]
 pub struct HarvestTask {
     pub task: HarvestTaskIdentification,
 }
@@ -15,7 +15,7 @@ impl HarvestTask {
     }
 }
 
-#[derive(Deserialize, Debug, PartialEq)]
+#[derive(Deserialize, Serialize, Debug, PartialEq)]
 pub struct HarvestTaskIdentification {
     pub id: u32,
     pub name: String,
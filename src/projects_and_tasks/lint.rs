@@ -0,0 +1,206 @@
+use crate::projects_and_tasks::lint_error::LintError;
+use std::collections::HashMap;
+
+#[derive(Debug, PartialEq)]
+pub struct ProjectOutline {
+    pub path: String,
+    pub id: Option<u32>,
+    pub name: String,
+    pub tasks: Vec<TaskOutline>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct TaskOutline {
+    pub path: String,
+    pub id: Option<u32>,
+    pub name: String,
+}
+
+pub fn lint(projects: &[ProjectOutline]) -> Vec<LintError> {
+    let mut errors = vec![];
+    let mut ids = Uniqueness::new("Project");
+    let mut names = Uniqueness::new("Project");
+
+    for project in projects {
+        errors.extend(check_name(&project.path, &project.name));
+        errors.extend(ids.check_id(&project.path, project.id));
+        errors.extend(names.check_name(&project.path, &project.name));
+        if project.tasks.is_empty() {
+            errors.push(LintError::NoTasks {
+                path: project.path.clone(),
+                name: project.name.clone(),
+            });
+        }
+        errors.extend(lint_tasks(&project.tasks));
+    }
+    errors
+}
+
+fn lint_tasks(tasks: &[TaskOutline]) -> Vec<LintError> {
+    let mut errors = vec![];
+    let mut ids = Uniqueness::new("Task");
+    let mut names = Uniqueness::new("Task");
+
+    for task in tasks {
+        errors.extend(check_name(&task.path, &task.name));
+        errors.extend(ids.check_id(&task.path, task.id));
+        errors.extend(names.check_name(&task.path, &task.name));
+    }
+    errors
+}
+
+fn check_name(path: &str, name: &str) -> Option<LintError> {
+    if name.trim().is_empty() {
+        return Some(LintError::EmptyName {
+            path: format!("{}.name", path),
+        });
+    }
+    None
+}
+
+struct Uniqueness {
+    kind: &'static str,
+    seen: HashMap<String, String>,
+}
+
+impl Uniqueness {
+    fn new(kind: &'static str) -> Self {
+        Self {
+            kind,
+            seen: HashMap::new(),
+        }
+    }
+
+    fn check_id(&mut self, path: &str, id: Option<u32>) -> Option<LintError> {
+        let id = id?;
+        let first_path = self.first_path(path, id.to_string())?;
+        Some(LintError::DuplicateId {
+            path: format!("{}.id", path),
+            kind: self.kind,
+            id,
+            first_path: format!("{}.id", first_path),
+        })
+    }
+
+    fn check_name(&mut self, path: &str, name: &str) -> Option<LintError> {
+        if name.trim().is_empty() {
+            return None;
+        }
+        let first_path = self.first_path(path, name.trim().to_lowercase())?;
+        Some(LintError::DuplicateName {
+            path: format!("{}.name", path),
+            kind: self.kind,
+            name: name.to_string(),
+            first_path: format!("{}.name", first_path),
+        })
+    }
+
+    fn first_path(&mut self, path: &str, key: String) -> Option<String> {
+        match self.seen.get(&key) {
+            Some(first_path) => Some(first_path.clone()),
+            None => {
+                self.seen.insert(key, path.to_string());
+                None
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn task(path: &str, id: Option<u32>, name: &str) -> TaskOutline {
+        TaskOutline {
+            path: path.to_string(),
+            id,
+            name: name.to_string(),
+        }
+    }
+
+    fn project(path: &str, id: Option<u32>, name: &str, tasks: Vec<TaskOutline>) -> ProjectOutline {
+        ProjectOutline {
+            path: path.to_string(),
+            id,
+            name: name.to_string(),
+            tasks,
+        }
+    }
+
+    #[test]
+    fn it_accepts_valid_projects() {
+        let projects = vec![
+            project("$[0]", Some(1), "A", vec![task("$[0].t[0]", Some(1), "T")]),
+            project("$[1]", Some(2), "B", vec![task("$[1].t[0]", Some(1), "T")]),
+        ];
+
+        assert_eq!(lint(&projects), vec![]);
+    }
+
+    #[test]
+    fn it_reports_duplicated_project_ids_and_names() {
+        let projects = vec![
+            project("$[0]", Some(1), "A", vec![task("$[0].t[0]", None, "T")]),
+            project("$[1]", Some(1), "a", vec![task("$[1].t[0]", None, "T")]),
+        ];
+
+        assert_eq!(
+            lint(&projects),
+            vec![
+                LintError::DuplicateId {
+                    path: "$[1].id".to_string(),
+                    kind: "Project",
+                    id: 1,
+                    first_path: "$[0].id".to_string()
+                },
+                LintError::DuplicateName {
+                    path: "$[1].name".to_string(),
+                    kind: "Project",
+                    name: "a".to_string(),
+                    first_path: "$[0].name".to_string()
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn it_reports_duplicated_tasks_within_a_project() {
+        let projects = vec![project(
+            "$[0]",
+            None,
+            "A",
+            vec![
+                task("$[0].t[0]", Some(3), "T"),
+                task("$[0].t[1]", Some(3), "U"),
+            ],
+        )];
+
+        assert_eq!(
+            lint(&projects),
+            vec![LintError::DuplicateId {
+                path: "$[0].t[1].id".to_string(),
+                kind: "Task",
+                id: 3,
+                first_path: "$[0].t[0].id".to_string()
+            }]
+        );
+    }
+
+    #[test]
+    fn it_reports_empty_names_and_projects_without_tasks() {
+        let projects = vec![project("$[0]", None, " ", vec![])];
+
+        assert_eq!(
+            lint(&projects),
+            vec![
+                LintError::EmptyName {
+                    path: "$[0].name".to_string()
+                },
+                LintError::NoTasks {
+                    path: "$[0]".to_string(),
+                    name: " ".to_string()
+                }
+            ]
+        );
+    }
+}
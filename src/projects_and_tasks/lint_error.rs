@@ -0,0 +1,55 @@
+use std::fmt;
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum LintError {
+    EmptyName {
+        path: String,
+    },
+    DuplicateId {
+        path: String,
+        kind: &'static str,
+        id: u32,
+        first_path: String,
+    },
+    DuplicateName {
+        path: String,
+        kind: &'static str,
+        name: String,
+        first_path: String,
+    },
+    NoTasks {
+        path: String,
+        name: String,
+    },
+}
+
+impl fmt::Display for LintError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self {
+            Self::EmptyName { path } => write!(f, "{} | LintError: Name is empty", path),
+            Self::DuplicateId {
+                path,
+                kind,
+                id,
+                first_path,
+            } => write!(
+                f,
+                "{} | LintError: {} id {} is already used at {}",
+                path, kind, id, first_path
+            ),
+            Self::DuplicateName {
+                path,
+                kind,
+                name,
+                first_path,
+            } => write!(
+                f,
+                "{} | LintError: {} name '{}' is already used at {}",
+                path, kind, name, first_path
+            ),
+            Self::NoTasks { path, name } => {
+                write!(f, "{} | LintError: Project '{}' has no tasks", path, name)
+            }
+        }
+    }
+}
@@ -1,7 +1,7 @@
 use crate::projects_and_tasks::{named::Named, project_error::SearchError};
 
 pub trait ListWithNames<T: Named> {
-    fn items(&self) -> std::slice::Iter<T>;
+    fn items(&self) -> std::slice::Iter<'_, T>;
 
     fn find(&self, search_string: &str) -> Result<&T, SearchError> {
         let found: Vec<&T> = self
@@ -10,7 +10,7 @@ pub trait ListWithNames<T: Named> {
             .collect();
 
         match found.len() {
-            1 => Ok(found.get(0).unwrap()),
+            1 => Ok(found.first().unwrap()),
             0 => Err(SearchError::NotFound),
             _ => Err(SearchError::FoundMoreThanOne),
         }
@@ -35,7 +35,7 @@ mod tests {
         items: Vec<NamedStruct>,
     }
     impl ListWithNames<NamedStruct> for ListWithNamesStruct {
-        fn items(&self) -> std::slice::Iter<NamedStruct> {
+        fn items(&self) -> std::slice::Iter<'_, NamedStruct> {
             self.items.iter()
         }
     }
@@ -0,0 +1,149 @@
+use crate::projects_and_tasks::{merge_error::MergeError, named::Named};
+
+pub trait Mergeable: Named + Sized {
+    const KIND: &'static str;
+
+    fn id(&self) -> Option<u32>;
+
+    fn absorb(&mut self, other: Self) -> Vec<MergeError>;
+
+    fn is_same_as(&self, other: &Self) -> bool {
+        match (self.id(), other.id()) {
+            (Some(id), Some(other_id)) if id == other_id => true,
+            _ => self.name().to_lowercase() == other.name().to_lowercase(),
+        }
+    }
+
+    fn conflict_with(&self, other: &Self) -> Option<MergeError> {
+        let (id, other_id) = (self.id()?, other.id()?);
+        if id != other_id {
+            return Some(MergeError::SameNameWithDifferentIds {
+                kind: Self::KIND,
+                name: self.name().to_string(),
+                id,
+                other_id,
+            });
+        }
+        if self.name().to_lowercase() != other.name().to_lowercase() {
+            return Some(MergeError::SameIdWithDifferentNames {
+                kind: Self::KIND,
+                id,
+                name: self.name().to_string(),
+                other_name: other.name().to_string(),
+            });
+        }
+        None
+    }
+}
+
+pub fn merge_into<T: Mergeable>(items: &mut Vec<T>, others: Vec<T>) -> Vec<MergeError> {
+    let mut errors = vec![];
+    for other in others {
+        match items.iter_mut().find(|item| item.is_same_as(&other)) {
+            Some(item) => match item.conflict_with(&other) {
+                Some(error) => errors.push(error),
+                None => errors.extend(item.absorb(other)),
+            },
+            None => items.push(other),
+        }
+    }
+    errors
+}
+
+pub fn merge_aliases(aliases: &mut Vec<String>, others: Vec<String>) {
+    for alias in others {
+        if !aliases.contains(&alias) {
+            aliases.push(alias);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    struct MergeableStruct {
+        id: Option<u32>,
+        name: String,
+    }
+    impl Named for MergeableStruct {
+        fn name(&self) -> &str {
+            &self.name
+        }
+    }
+    impl Mergeable for MergeableStruct {
+        const KIND: &'static str = "Item";
+
+        fn id(&self) -> Option<u32> {
+            self.id
+        }
+
+        fn absorb(&mut self, other: Self) -> Vec<MergeError> {
+            self.id = self.id.or(other.id);
+            vec![]
+        }
+    }
+
+    fn item(id: Option<u32>, name: &str) -> MergeableStruct {
+        MergeableStruct {
+            id,
+            name: name.to_string(),
+        }
+    }
+
+    #[test]
+    fn it_appends_unknown_items() {
+        let mut items = vec![item(Some(1), "A")];
+
+        let errors = merge_into(&mut items, vec![item(Some(2), "B")]);
+
+        assert_eq!(errors, vec![]);
+        assert_eq!(items, vec![item(Some(1), "A"), item(Some(2), "B")]);
+    }
+
+    #[test]
+    fn it_merges_items_with_same_name_when_one_has_no_id() {
+        let mut items = vec![item(None, "A")];
+
+        let errors = merge_into(&mut items, vec![item(Some(3), "a")]);
+
+        assert_eq!(errors, vec![]);
+        assert_eq!(items, vec![item(Some(3), "A")]);
+    }
+
+    #[test]
+    fn it_reports_same_id_with_different_names() {
+        let mut items = vec![item(Some(1), "A")];
+
+        let errors = merge_into(&mut items, vec![item(Some(1), "B")]);
+
+        assert_eq!(
+            errors,
+            vec![MergeError::SameIdWithDifferentNames {
+                kind: "Item",
+                id: 1,
+                name: "A".to_string(),
+                other_name: "B".to_string()
+            }]
+        );
+        assert_eq!(items, vec![item(Some(1), "A")]);
+    }
+
+    #[test]
+    fn it_reports_same_name_with_different_ids() {
+        let mut items = vec![item(Some(1), "A")];
+
+        let errors = merge_into(&mut items, vec![item(Some(2), "A")]);
+
+        assert_eq!(
+            errors,
+            vec![MergeError::SameNameWithDifferentIds {
+                kind: "Item",
+                name: "A".to_string(),
+                id: 1,
+                other_id: 2
+            }]
+        );
+    }
+}
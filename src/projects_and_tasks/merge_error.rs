@@ -0,0 +1,44 @@
+use std::fmt;
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum MergeError {
+    SameIdWithDifferentNames {
+        kind: &'static str,
+        id: u32,
+        name: String,
+        other_name: String,
+    },
+    SameNameWithDifferentIds {
+        kind: &'static str,
+        name: String,
+        id: u32,
+        other_id: u32,
+    },
+}
+
+impl fmt::Display for MergeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self {
+            Self::SameIdWithDifferentNames {
+                kind,
+                id,
+                name,
+                other_name,
+            } => write!(
+                f,
+                "{:<10} | MergeError: Id {} is named both '{}' and '{}'",
+                kind, id, name, other_name
+            ),
+            Self::SameNameWithDifferentIds {
+                kind,
+                name,
+                id,
+                other_id,
+            } => write!(
+                f,
+                "{:<10} | MergeError: '{}' has both ids {} and {}",
+                kind, name, id, other_id
+            ),
+        }
+    }
+}
@@ -1,8 +1,17 @@
+pub mod clockify;
 pub mod harvest;
+pub mod lint;
+pub mod lint_error;
 pub mod list_with_names;
+pub mod merge;
+pub mod merge_error;
 pub mod named;
+pub mod native;
 pub mod project;
 pub mod project_error;
 pub mod projects;
+pub mod projects_file;
+pub mod projects_file_error;
 pub mod task;
 pub mod tasks;
+pub mod toggl;
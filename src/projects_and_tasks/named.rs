@@ -1,8 +1,15 @@
 pub trait Named {
     fn name(&self) -> &str;
 
+    fn aliases(&self) -> &[String] {
+        &[]
+    }
+
     fn has_name_with(&self, string: &str) -> bool {
-        self.name().to_lowercase().contains(&string.to_lowercase())
+        let string = string.to_lowercase();
+        std::iter::once(self.name())
+            .chain(self.aliases().iter().map(String::as_str))
+            .any(|name| name.to_lowercase().contains(&string))
     }
 }
 
@@ -19,6 +26,20 @@ mod tests {
         }
     }
 
+    struct AliasedStruct {
+        name: String,
+        aliases: Vec<String>,
+    }
+    impl Named for AliasedStruct {
+        fn name(&self) -> &str {
+            &self.name
+        }
+
+        fn aliases(&self) -> &[String] {
+            &self.aliases
+        }
+    }
+
     #[test]
     fn has_name_when_name_includes_given_string() {
         let named_instance = NamedStruct {
@@ -27,7 +48,7 @@ mod tests {
 
         let has_name = named_instance.has_name_with("new");
 
-        assert_eq!(has_name, true);
+        assert!(has_name);
     }
 
     #[test]
@@ -38,6 +59,18 @@ mod tests {
 
         let has_name = named_instance.has_name_with("abc");
 
-        assert_eq!(has_name, false);
+        assert!(!has_name);
+    }
+
+    #[test]
+    fn has_name_when_an_alias_includes_given_string() {
+        let named_instance = AliasedStruct {
+            name: "New project".to_string(),
+            aliases: vec!["TV".to_string(), "Timeval".to_string()],
+        };
+
+        let has_name = named_instance.has_name_with("timeval");
+
+        assert!(has_name);
     }
 }
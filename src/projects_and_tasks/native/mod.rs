@@ -0,0 +1,3 @@
+mod project;
+pub mod projects;
+mod task;
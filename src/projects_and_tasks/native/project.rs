@@ -0,0 +1,80 @@
+use crate::projects_and_tasks::native::task::NativeTask;
+use crate::projects_and_tasks::{
+    project::{ProjectWithTasks, ProjectWithTasksBuilder},
+    task::Task,
+    tasks::TasksBuilder,
+};
+use serde::Deserialize;
+
+#[derive(Deserialize, Debug, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct NativeProject {
+    #[serde(default)]
+    pub id: Option<u32>,
+    pub name: String,
+    #[serde(default)]
+    pub aliases: Vec<String>,
+    #[serde(default)]
+    pub jira_projects: Vec<String>,
+    #[serde(default)]
+    pub tasks: Vec<NativeTask>,
+}
+
+impl NativeProject {
+    pub fn into_project(self) -> ProjectWithTasks {
+        let tasks: Vec<Task> = self
+            .tasks
+            .into_iter()
+            .map(|native_task| native_task.into_task())
+            .collect();
+
+        ProjectWithTasksBuilder::new()
+            .with_optional_id(self.id)
+            .with_name(self.name)
+            .with_aliases(self.aliases)
+            .with_jira_projects(self.jira_projects)
+            .with_tasks(TasksBuilder::new().with_tasks(tasks).build())
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::projects_and_tasks::task::TaskBuilder;
+
+    #[test]
+    fn it_creates_project() {
+        let native_project = NativeProject {
+            id: Some(12),
+            name: "project".to_string(),
+            aliases: vec!["p".to_string()],
+            jira_projects: vec!["TV".to_string()],
+            tasks: vec![NativeTask {
+                id: Some(999),
+                name: "task".to_string(),
+                aliases: vec![],
+            }],
+        };
+
+        let project = native_project.into_project();
+
+        assert_eq!(
+            project,
+            ProjectWithTasksBuilder::new()
+                .with_id(12)
+                .with_name("project".to_string())
+                .with_aliases(vec!["p".to_string()])
+                .with_jira_projects(vec!["TV".to_string()])
+                .with_tasks(
+                    TasksBuilder::new()
+                        .with_tasks(vec![TaskBuilder::new()
+                            .with_id(999)
+                            .with_name("task".to_string())
+                            .build()])
+                        .build()
+                )
+                .build()
+        );
+    }
+}
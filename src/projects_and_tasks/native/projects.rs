@@ -0,0 +1,181 @@
+use crate::projects_and_tasks::native::project::NativeProject;
+use crate::projects_and_tasks::{
+    lint::{ProjectOutline, TaskOutline},
+    project::ProjectWithTasks,
+    projects::{Projects, ProjectsBuilder},
+};
+use serde::Deserialize;
+
+#[derive(Deserialize, Debug, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct NativeProjects {
+    projects: Vec<NativeProject>,
+}
+
+impl NativeProjects {
+    pub fn from_toml(string: &str) -> Result<NativeProjects, toml::de::Error> {
+        toml::from_str(string)
+    }
+
+    pub fn from_yaml(string: &str) -> Result<NativeProjects, serde_yaml::Error> {
+        serde_yaml::from_str(string)
+    }
+
+    pub fn to_projects(self) -> Projects {
+        let projects: Vec<ProjectWithTasks> = self
+            .projects
+            .into_iter()
+            .map(|native_project| native_project.into_project())
+            .collect();
+
+        ProjectsBuilder::new().with_projects(projects).build()
+    }
+
+    pub fn outline(&self) -> Vec<ProjectOutline> {
+        self.projects
+            .iter()
+            .enumerate()
+            .map(|(i, native_project)| {
+                let path = format!("$.projects[{}]", i);
+                ProjectOutline {
+                    id: native_project.id,
+                    name: native_project.name.clone(),
+                    tasks: native_project
+                        .tasks
+                        .iter()
+                        .enumerate()
+                        .map(|(j, native_task)| TaskOutline {
+                            path: format!("{}.tasks[{}]", path, j),
+                            id: native_task.id,
+                            name: native_task.name.clone(),
+                        })
+                        .collect(),
+                    path,
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::projects_and_tasks::{
+        project::ProjectWithTasksBuilder, task::TaskBuilder, tasks::TasksBuilder,
+    };
+
+    fn expected_projects() -> Projects {
+        ProjectsBuilder::new()
+            .with_projects(vec![
+                ProjectWithTasksBuilder::new()
+                    .with_id(1)
+                    .with_name("My Timeval project".to_string())
+                    .with_aliases(vec!["TV".to_string()])
+                    .with_jira_projects(vec!["TV".to_string()])
+                    .with_tasks(
+                        TasksBuilder::new()
+                            .with_tasks(vec![TaskBuilder::new()
+                                .with_id(1)
+                                .with_name("Build stuff".to_string())
+                                .with_aliases(vec!["Develop".to_string()])
+                                .build()])
+                            .build(),
+                    )
+                    .build(),
+                ProjectWithTasksBuilder::new()
+                    .with_optional_id(None)
+                    .with_name("Training".to_string())
+                    .with_tasks(
+                        TasksBuilder::new()
+                            .with_tasks(vec![TaskBuilder::new()
+                                .with_optional_id(None)
+                                .with_name("Self-study".to_string())
+                                .build()])
+                            .build(),
+                    )
+                    .build(),
+            ])
+            .build()
+    }
+
+    #[test]
+    fn it_parses_toml_into_project_list() {
+        let toml = r#"
+[[projects]]
+id = 1
+name = "My Timeval project"
+aliases = ["TV"]
+jira_projects = ["TV"]
+
+[[projects.tasks]]
+id = 1
+name = "Build stuff"
+aliases = ["Develop"]
+
+[[projects]]
+name = "Training"
+tasks = [{ name = "Self-study" }]
+"#;
+
+        let projects = NativeProjects::from_toml(toml).unwrap().to_projects();
+
+        assert_eq!(projects, expected_projects());
+    }
+
+    #[test]
+    fn it_parses_yaml_into_project_list() {
+        let yaml = r#"
+projects:
+  - id: 1
+    name: My Timeval project
+    aliases: [TV]
+    jira_projects: [TV]
+    tasks:
+      - id: 1
+        name: Build stuff
+        aliases: [Develop]
+  - name: Training
+    tasks:
+      - name: Self-study
+"#;
+
+        let projects = NativeProjects::from_yaml(yaml).unwrap().to_projects();
+
+        assert_eq!(projects, expected_projects());
+    }
+
+    #[test]
+    fn it_rejects_unknown_keys() {
+        let toml = r#"
+[[projects]]
+name = "My Timeval project"
+ticket_pattern = "TV-[0-9]+"
+"#;
+
+        let error = NativeProjects::from_toml(toml).unwrap_err();
+
+        assert!(error.to_string().contains("unknown field `ticket_pattern`"));
+    }
+
+    #[test]
+    fn it_outlines_projects_with_paths() {
+        let native_projects = NativeProjects::from_yaml(
+            "projects:\n  - name: A\n    tasks:\n      - id: 3\n        name: T\n",
+        )
+        .unwrap();
+
+        assert_eq!(
+            native_projects.outline(),
+            vec![ProjectOutline {
+                path: "$.projects[0]".to_string(),
+                id: None,
+                name: "A".to_string(),
+                tasks: vec![TaskOutline {
+                    path: "$.projects[0].tasks[0]".to_string(),
+                    id: Some(3),
+                    name: "T".to_string(),
+                }]
+            }]
+        );
+    }
+}
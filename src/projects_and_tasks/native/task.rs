@@ -0,0 +1,47 @@
+use crate::projects_and_tasks::task::{Task, TaskBuilder};
+use serde::Deserialize;
+
+#[derive(Deserialize, Debug, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct NativeTask {
+    #[serde(default)]
+    pub id: Option<u32>,
+    pub name: String,
+    #[serde(default)]
+    pub aliases: Vec<String>,
+}
+
+impl NativeTask {
+    pub fn into_task(self) -> Task {
+        TaskBuilder::new()
+            .with_optional_id(self.id)
+            .with_name(self.name)
+            .with_aliases(self.aliases)
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_creates_task_without_id() {
+        let native_task = NativeTask {
+            id: None,
+            name: "task".to_string(),
+            aliases: vec!["t".to_string()],
+        };
+
+        let task = native_task.into_task();
+
+        assert_eq!(
+            task,
+            TaskBuilder::new()
+                .with_optional_id(None)
+                .with_name("task".to_string())
+                .with_aliases(vec!["t".to_string()])
+                .build()
+        );
+    }
+}
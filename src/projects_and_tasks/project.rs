@@ -1,5 +1,7 @@
 use crate::projects_and_tasks::{
     list_with_names::ListWithNames,
+    merge::{merge_aliases, Mergeable},
+    merge_error::MergeError,
     named::Named,
     project_error::ProjectError,
     task::Task,
@@ -8,8 +10,10 @@ use crate::projects_and_tasks::{
 
 #[derive(Debug, PartialEq, Clone)]
 pub struct ProjectWithTasks {
-    id: u32,
+    id: Option<u32>,
     name: String,
+    aliases: Vec<String>,
+    jira_projects: Vec<String>,
     tasks: Tasks,
 }
 
@@ -17,49 +21,62 @@ impl Named for ProjectWithTasks {
     fn name(&self) -> &str {
         &self.name
     }
+
+    fn aliases(&self) -> &[String] {
+        &self.aliases
+    }
+}
+
+impl Mergeable for ProjectWithTasks {
+    const KIND: &'static str = "Project";
+
+    fn id(&self) -> Option<u32> {
+        self.id
+    }
+
+    fn absorb(&mut self, other: Self) -> Vec<MergeError> {
+        self.id = self.id.or(other.id);
+        merge_aliases(&mut self.aliases, other.aliases);
+        merge_aliases(&mut self.jira_projects, other.jira_projects);
+        self.tasks.merge(other.tasks)
+    }
 }
 
 impl ProjectWithTasks {
     pub fn find_task(&self, search_string: &str) -> Result<&Task, ProjectError> {
         self.tasks.find(search_string).map_err(ProjectError::Task)
     }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::projects_and_tasks::task::TaskBuilder;
-
-    #[test]
-    fn it_finds_task_with_specific_string() {
-        let expected_task = TaskBuilder::new()
-            .with_name("Task with some speciality".to_string())
-            .build();
-        let project = ProjectWithTasksBuilder::new()
-            .with_tasks(
-                TasksBuilder::new()
-                    .with_tasks(vec![expected_task.clone()])
-                    .build(),
-            )
-            .build();
 
-        let task = project.find_task("Special");
+    pub fn find_task_by_id(&self, id: u32) -> Result<&Task, ProjectError> {
+        self.tasks.find_by_id(id).map_err(ProjectError::Task)
+    }
 
-        assert_eq!(task, Ok(&expected_task));
+    pub fn jira_projects(&self) -> &[String] {
+        &self.jira_projects
     }
 }
 
 pub struct ProjectWithTasksBuilder {
-    id: u32,
+    id: Option<u32>,
     name: String,
+    aliases: Vec<String>,
+    jira_projects: Vec<String>,
     tasks: Tasks,
 }
 
+impl Default for ProjectWithTasksBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl ProjectWithTasksBuilder {
     pub fn new() -> Self {
         Self {
-            id: 1,
+            id: Some(1),
             name: "project".to_string(),
+            aliases: vec![],
+            jira_projects: vec![],
             tasks: TasksBuilder::empty().build(),
         }
     }
@@ -70,10 +87,25 @@ impl ProjectWithTasksBuilder {
     }
 
     pub fn with_id(mut self, id: u32) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    pub fn with_optional_id(mut self, id: Option<u32>) -> Self {
         self.id = id;
         self
     }
 
+    pub fn with_aliases(mut self, aliases: Vec<String>) -> Self {
+        self.aliases = aliases;
+        self
+    }
+
+    pub fn with_jira_projects(mut self, jira_projects: Vec<String>) -> Self {
+        self.jira_projects = jira_projects;
+        self
+    }
+
     pub fn with_tasks(mut self, tasks: Tasks) -> Self {
         self.tasks = tasks;
         self
@@ -83,6 +115,8 @@ impl ProjectWithTasksBuilder {
         ProjectWithTasks {
             id: self.id,
             name: self.name,
+            aliases: self.aliases,
+            jira_projects: self.jira_projects,
             tasks: self.tasks,
         }
     }
@@ -90,7 +124,7 @@ impl ProjectWithTasksBuilder {
 
 #[derive(Debug, PartialEq, Clone)]
 pub struct Project {
-    id: u32,
+    id: Option<u32>,
     name: String,
 }
 
@@ -101,17 +135,31 @@ impl Project {
             name: project_with_tasks.name.clone(),
         }
     }
+
+    pub fn id(&self) -> Option<u32> {
+        self.id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
 }
 
 pub struct ProjectBuilder {
-    id: u32,
+    id: Option<u32>,
     name: String,
 }
 
+impl Default for ProjectBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl ProjectBuilder {
     pub fn new() -> Self {
         Self {
-            id: 1,
+            id: Some(1),
             name: "project".to_string(),
         }
     }
@@ -122,7 +170,7 @@ impl ProjectBuilder {
     }
 
     pub fn with_id(mut self, id: u32) -> Self {
-        self.id = id;
+        self.id = Some(id);
         self
     }
 
@@ -133,3 +181,27 @@ impl ProjectBuilder {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::projects_and_tasks::task::TaskBuilder;
+
+    #[test]
+    fn it_finds_task_with_specific_string() {
+        let expected_task = TaskBuilder::new()
+            .with_name("Task with some speciality".to_string())
+            .build();
+        let project = ProjectWithTasksBuilder::new()
+            .with_tasks(
+                TasksBuilder::new()
+                    .with_tasks(vec![expected_task.clone()])
+                    .build(),
+            )
+            .build();
+
+        let task = project.find_task("Special");
+
+        assert_eq!(task, Ok(&expected_task));
+    }
+}
@@ -1,7 +1,9 @@
 use crate::projects_and_tasks::{
     list_with_names::ListWithNames,
+    merge::{merge_into, Mergeable},
+    merge_error::MergeError,
     project::{Project, ProjectWithTasks},
-    project_error::ProjectError,
+    project_error::{ProjectError, SearchError},
     task::Task,
 };
 
@@ -11,7 +13,7 @@ pub struct Projects {
 }
 
 impl ListWithNames<ProjectWithTasks> for Projects {
-    fn items(&self) -> std::slice::Iter<ProjectWithTasks> {
+    fn items(&self) -> std::slice::Iter<'_, ProjectWithTasks> {
         self.projects.iter()
     }
 }
@@ -22,11 +24,75 @@ impl Projects {
         project_string: &str,
         task_string: &str,
     ) -> Result<(Project, Task), ProjectError> {
-        let project_with_tasks = self.find(project_string).map_err(ProjectError::Project)?;
+        let project_with_tasks = match reference_id(project_string) {
+            Some(id) => self.find_by_id(id),
+            None => self.find(project_string),
+        }
+        .map_err(ProjectError::Project)?;
+        let project = Project::new(project_with_tasks);
+        let task = match reference_id(task_string) {
+            Some(id) => project_with_tasks.find_task_by_id(id)?,
+            None => project_with_tasks.find_task(task_string)?,
+        };
+        Ok((project, task.clone()))
+    }
+
+    pub fn get_project_with_task_by_ids(
+        &self,
+        project_id: u32,
+        task_id: u32,
+    ) -> Result<(Project, Task), ProjectError> {
+        let project_with_tasks = self.find_by_id(project_id).map_err(ProjectError::Project)?;
         let project = Project::new(project_with_tasks);
-        let task = project_with_tasks.find_task(task_string)?;
+        let task = project_with_tasks.find_task_by_id(task_id)?;
         Ok((project, task.clone()))
     }
+
+    fn find_by_id(&self, id: u32) -> Result<&ProjectWithTasks, SearchError> {
+        self.projects
+            .iter()
+            .find(|project| project.id() == Some(id))
+            .ok_or(SearchError::NotFound)
+    }
+
+    pub fn merge(&mut self, other: Projects) -> Vec<MergeError> {
+        merge_into(&mut self.projects, other.projects)
+    }
+}
+
+pub fn reference_id(string: &str) -> Option<u32> {
+    string.strip_prefix('#').and_then(|id| id.parse().ok())
+}
+
+pub struct ProjectsBuilder {
+    projects: Vec<ProjectWithTasks>,
+}
+
+impl Default for ProjectsBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ProjectsBuilder {
+    pub fn new() -> Self {
+        Self::empty()
+    }
+
+    pub fn empty() -> Self {
+        Self { projects: vec![] }
+    }
+
+    pub fn with_projects(mut self, projects: Vec<ProjectWithTasks>) -> Self {
+        self.projects = projects;
+        self
+    }
+
+    pub fn build(self) -> Projects {
+        Projects {
+            projects: self.projects,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -70,29 +136,117 @@ mod tests {
         assert_eq!(project, Project::new(&project_to_be_found));
         assert_eq!(task, task_to_be_found);
     }
-}
 
-pub struct ProjectsBuilder {
-    projects: Vec<ProjectWithTasks>,
-}
+    #[test]
+    fn it_finds_project_and_task_by_ids() {
+        let task_to_be_found = TaskBuilder::new()
+            .with_id(12)
+            .with_name("Task".to_string())
+            .build();
+        let project_to_be_found = ProjectWithTasksBuilder::new()
+            .with_id(3)
+            .with_tasks(
+                TasksBuilder::new()
+                    .with_tasks(vec![
+                        TaskBuilder::new().with_id(11).build(),
+                        task_to_be_found.clone(),
+                    ])
+                    .build(),
+            )
+            .build();
+        let projects = ProjectsBuilder::new()
+            .with_projects(vec![
+                ProjectWithTasksBuilder::new().with_id(2).build(),
+                project_to_be_found.clone(),
+            ])
+            .build();
 
-impl ProjectsBuilder {
-    pub fn new() -> Self {
-        Self::empty()
+        assert_eq!(
+            projects.get_project_with_task_by_ids(3, 12),
+            Ok((Project::new(&project_to_be_found), task_to_be_found.clone()))
+        );
+        assert_eq!(
+            projects.get_project_with_task_by_ids(3, 13),
+            Err(ProjectError::Task(SearchError::NotFound))
+        );
+        assert_eq!(
+            projects.get_project_with_task("#3", "#12"),
+            Ok((Project::new(&project_to_be_found), task_to_be_found))
+        );
     }
 
-    pub fn empty() -> Self {
-        Self { projects: vec![] }
-    }
+    #[test]
+    fn it_merges_tasks_of_same_project() {
+        let mut projects = ProjectsBuilder::new()
+            .with_projects(vec![ProjectWithTasksBuilder::new()
+                .with_id(1)
+                .with_name("Project".to_string())
+                .with_tasks(
+                    TasksBuilder::new()
+                        .with_tasks(vec![TaskBuilder::new()
+                            .with_id(1)
+                            .with_name("Build".to_string())
+                            .build()])
+                        .build(),
+                )
+                .build()])
+            .build();
+        let other = ProjectsBuilder::new()
+            .with_projects(vec![ProjectWithTasksBuilder::new()
+                .with_optional_id(None)
+                .with_name("project".to_string())
+                .with_aliases(vec!["P".to_string()])
+                .with_tasks(
+                    TasksBuilder::new()
+                        .with_tasks(vec![
+                            TaskBuilder::new()
+                                .with_id(2)
+                                .with_name("Build".to_string())
+                                .build(),
+                            TaskBuilder::new()
+                                .with_optional_id(None)
+                                .with_name("Internal".to_string())
+                                .build(),
+                        ])
+                        .build(),
+                )
+                .build()])
+            .build();
 
-    pub fn with_projects(mut self, projects: Vec<ProjectWithTasks>) -> Self {
-        self.projects = projects;
-        self
-    }
+        let errors = projects.merge(other);
 
-    pub fn build(self) -> Projects {
-        Projects {
-            projects: self.projects,
-        }
+        assert_eq!(
+            errors,
+            vec![MergeError::SameNameWithDifferentIds {
+                kind: "Task",
+                name: "Build".to_string(),
+                id: 1,
+                other_id: 2
+            }]
+        );
+        assert_eq!(
+            projects,
+            ProjectsBuilder::new()
+                .with_projects(vec![ProjectWithTasksBuilder::new()
+                    .with_id(1)
+                    .with_name("Project".to_string())
+                    .with_aliases(vec!["P".to_string()])
+                    .with_tasks(
+                        TasksBuilder::new()
+                            .with_tasks(vec![
+                                TaskBuilder::new()
+                                    .with_id(1)
+                                    .with_name("Build".to_string())
+                                    .build(),
+                                TaskBuilder::new()
+                                    .with_optional_id(None)
+                                    .with_name("Internal".to_string())
+                                    .build(),
+                            ])
+                            .build()
+                    )
+                    .build()])
+                .build()
+        );
     }
 }
@@ -0,0 +1,214 @@
+use crate::projects_and_tasks::{
+    clockify::projects::ClockifyProjects,
+    harvest::projects::HarvestProjectAssignments,
+    lint::{lint, ProjectOutline},
+    lint_error::LintError,
+    native::projects::NativeProjects,
+    projects::Projects,
+    projects_file_error::ProjectsFileError,
+    toggl::projects::TogglProjects,
+};
+use serde_json::Value;
+use std::path::Path;
+
+#[derive(Debug, PartialEq)]
+pub enum ProjectsFile {
+    Harvest(HarvestProjectAssignments),
+    Native(NativeProjects),
+    Toggl(TogglProjects),
+    Clockify(ClockifyProjects),
+}
+
+impl ProjectsFile {
+    pub fn parse(text: &str, file_name: &str) -> Result<Self, ProjectsFileError> {
+        match Format::detect(text, file_name) {
+            Format::Json => match Tracker::detect(text) {
+                Tracker::Harvest => HarvestProjectAssignments::from(text)
+                    .map(Self::Harvest)
+                    .map_err(ProjectsFileError::Json),
+                Tracker::Toggl => TogglProjects::from(text)
+                    .map(Self::Toggl)
+                    .map_err(ProjectsFileError::Toggl),
+                Tracker::Clockify => ClockifyProjects::from(text)
+                    .map(Self::Clockify)
+                    .map_err(ProjectsFileError::Clockify),
+            },
+            Format::Toml => NativeProjects::from_toml(text)
+                .map(Self::Native)
+                .map_err(ProjectsFileError::Toml),
+            Format::Yaml => NativeProjects::from_yaml(text)
+                .map(Self::Native)
+                .map_err(ProjectsFileError::Yaml),
+        }
+    }
+
+    pub fn lint(&self) -> Vec<LintError> {
+        lint(&self.outline())
+    }
+
+    fn outline(&self) -> Vec<ProjectOutline> {
+        match self {
+            Self::Harvest(harvest_projects) => harvest_projects.outline(),
+            Self::Native(native_projects) => native_projects.outline(),
+            Self::Toggl(toggl_projects) => toggl_projects.outline(),
+            Self::Clockify(clockify_projects) => clockify_projects.outline(),
+        }
+    }
+
+    pub fn into_projects(self) -> Projects {
+        match self {
+            Self::Harvest(harvest_projects) => harvest_projects.to_projects(),
+            Self::Native(native_projects) => native_projects.to_projects(),
+            Self::Toggl(toggl_projects) => toggl_projects.to_projects(),
+            Self::Clockify(clockify_projects) => clockify_projects.to_projects(),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum Format {
+    Json,
+    Toml,
+    Yaml,
+}
+
+impl Format {
+    fn detect(text: &str, file_name: &str) -> Self {
+        Self::from_extension(file_name).unwrap_or_else(|| Self::from_content(text))
+    }
+
+    fn from_extension(file_name: &str) -> Option<Self> {
+        let extension = Path::new(file_name).extension()?.to_str()?;
+        match extension.to_lowercase().as_str() {
+            "json" => Some(Self::Json),
+            "toml" => Some(Self::Toml),
+            "yaml" | "yml" => Some(Self::Yaml),
+            _ => None,
+        }
+    }
+
+    fn from_content(text: &str) -> Self {
+        if text.trim_start().starts_with('{') || serde_json::from_str::<Value>(text).is_ok() {
+            Self::Json
+        } else if text.parse::<toml::Value>().is_ok() {
+            Self::Toml
+        } else {
+            Self::Yaml
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum Tracker {
+    Harvest,
+    Toggl,
+    Clockify,
+}
+
+impl Tracker {
+    fn detect(text: &str) -> Self {
+        match serde_json::from_str::<Value>(text) {
+            Ok(Value::Array(projects)) => Self::from_project(projects.first()),
+            Ok(Value::Object(export)) if !export.contains_key("project_assignments") => {
+                match export.get("projects") {
+                    Some(Value::Array(projects)) => Self::from_project(projects.first()),
+                    _ => Self::Harvest,
+                }
+            }
+            _ => Self::Harvest,
+        }
+    }
+
+    fn from_project(project: Option<&Value>) -> Self {
+        match project {
+            Some(project) if project.get("workspaceId").is_some() => Self::Clockify,
+            Some(project) if project.get("id").is_some_and(Value::is_string) => Self::Clockify,
+            _ => Self::Toggl,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_detects_format_by_extension() {
+        assert_eq!(Format::detect("", "projects.json"), Format::Json);
+        assert_eq!(Format::detect("", "projects.TOML"), Format::Toml);
+        assert_eq!(Format::detect("", "projects.yml"), Format::Yaml);
+    }
+
+    #[test]
+    fn it_detects_format_by_content_without_known_extension() {
+        assert_eq!(
+            Format::detect(r#" { "project_assignments": [] }"#, "projects"),
+            Format::Json
+        );
+        assert_eq!(
+            Format::detect("[[projects]]\nname = \"A\"\n", "projects.txt"),
+            Format::Toml
+        );
+        assert_eq!(
+            Format::detect("projects:\n  - name: A\n", "projects"),
+            Format::Yaml
+        );
+    }
+
+    #[test]
+    fn it_detects_tracker_of_json_exports() {
+        assert_eq!(
+            Tracker::detect(r#"{ "project_assignments": [] }"#),
+            Tracker::Harvest
+        );
+        assert_eq!(
+            Tracker::detect(r#"[{ "id": 1, "workspace_id": 2, "name": "A" }]"#),
+            Tracker::Toggl
+        );
+        assert_eq!(
+            Tracker::detect(r#"{ "projects": [{ "id": 1, "name": "A" }], "tasks": [] }"#),
+            Tracker::Toggl
+        );
+        assert_eq!(
+            Tracker::detect(r#"[{ "id": "5b64", "workspaceId": "5b16", "name": "A" }]"#),
+            Tracker::Clockify
+        );
+        assert_eq!(Tracker::detect("not json"), Tracker::Harvest);
+    }
+
+    #[test]
+    fn it_parses_toggl_and_clockify_exports() {
+        let toggl = ProjectsFile::parse(r#"[{ "id": 1, "name": "A" }]"#, "projects.json");
+        let clockify = ProjectsFile::parse(r#"[{ "id": "5b64", "name": "A" }]"#, "projects");
+
+        assert!(matches!(toggl, Ok(ProjectsFile::Toggl(_))));
+        assert!(matches!(clockify, Ok(ProjectsFile::Clockify(_))));
+    }
+
+    #[test]
+    fn it_parses_native_projects() {
+        let file = ProjectsFile::parse("projects:\n  - name: A\n", "projects.yaml");
+
+        assert!(matches!(file, Ok(ProjectsFile::Native(_))));
+    }
+
+    #[test]
+    fn it_reports_errors_of_the_detected_format() {
+        let file = ProjectsFile::parse("projects = 3", "projects.toml");
+
+        assert!(matches!(file, Err(ProjectsFileError::Toml(_))));
+    }
+
+    #[test]
+    fn it_lints_the_parsed_file() {
+        let file = ProjectsFile::parse("projects:\n  - name: A\n", "projects.yaml").unwrap();
+
+        assert_eq!(
+            file.lint(),
+            vec![LintError::NoTasks {
+                path: "$.projects[0]".to_string(),
+                name: "A".to_string()
+            }]
+        );
+    }
+}
@@ -0,0 +1,22 @@
+use std::fmt;
+
+#[derive(Debug)]
+pub enum ProjectsFileError {
+    Json(serde_json::Error),
+    Toggl(serde_json::Error),
+    Clockify(serde_json::Error),
+    Toml(toml::de::Error),
+    Yaml(serde_yaml::Error),
+}
+
+impl fmt::Display for ProjectsFileError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self {
+            Self::Json(error) => write!(f, "Invalid Harvest json: {}", error),
+            Self::Toggl(error) => write!(f, "Invalid Toggl Track json: {}", error),
+            Self::Clockify(error) => write!(f, "Invalid Clockify json: {}", error),
+            Self::Toml(error) => write!(f, "Invalid toml: {}", error),
+            Self::Yaml(error) => write!(f, "Invalid yaml: {}", error),
+        }
+    }
+}
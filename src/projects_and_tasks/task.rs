@@ -1,31 +1,73 @@
-use crate::projects_and_tasks::named::Named;
+use crate::projects_and_tasks::{
+    merge::{merge_aliases, Mergeable},
+    merge_error::MergeError,
+    named::Named,
+};
 
 #[derive(Debug, PartialEq, Clone)]
 pub struct Task {
-    id: u32,
+    id: Option<u32>,
     name: String,
+    aliases: Vec<String>,
 }
 
 impl Named for Task {
     fn name(&self) -> &str {
         &self.name
     }
+
+    fn aliases(&self) -> &[String] {
+        &self.aliases
+    }
+}
+
+impl Task {
+    pub fn id(&self) -> Option<u32> {
+        self.id
+    }
+}
+
+impl Mergeable for Task {
+    const KIND: &'static str = "Task";
+
+    fn id(&self) -> Option<u32> {
+        self.id
+    }
+
+    fn absorb(&mut self, other: Self) -> Vec<MergeError> {
+        self.id = self.id.or(other.id);
+        merge_aliases(&mut self.aliases, other.aliases);
+        vec![]
+    }
 }
 
 pub struct TaskBuilder {
-    id: u32,
+    id: Option<u32>,
     name: String,
+    aliases: Vec<String>,
+}
+
+impl Default for TaskBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl TaskBuilder {
     pub fn new() -> Self {
         Self {
-            id: 1,
+            id: Some(1),
             name: "task".to_string(),
+            aliases: vec![],
         }
     }
 
     pub fn with_id(mut self, id: u32) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    pub fn with_optional_id(mut self, id: Option<u32>) -> Self {
         self.id = id;
         self
     }
@@ -35,10 +77,16 @@ impl TaskBuilder {
         self
     }
 
+    pub fn with_aliases(mut self, aliases: Vec<String>) -> Self {
+        self.aliases = aliases;
+        self
+    }
+
     pub fn build(self) -> Task {
         Task {
             id: self.id,
             name: self.name,
+            aliases: self.aliases,
         }
     }
 }
@@ -1,4 +1,7 @@
-use crate::projects_and_tasks::{list_with_names::ListWithNames, task::Task};
+use crate::projects_and_tasks::{
+    list_with_names::ListWithNames, merge::merge_into, merge_error::MergeError,
+    project_error::SearchError, task::Task,
+};
 
 #[derive(Debug, PartialEq, Clone)]
 pub struct Tasks {
@@ -6,15 +9,34 @@ pub struct Tasks {
 }
 
 impl ListWithNames<Task> for Tasks {
-    fn items(&self) -> std::slice::Iter<Task> {
+    fn items(&self) -> std::slice::Iter<'_, Task> {
         self.tasks.iter()
     }
 }
 
+impl Tasks {
+    pub fn merge(&mut self, other: Tasks) -> Vec<MergeError> {
+        merge_into(&mut self.tasks, other.tasks)
+    }
+
+    pub fn find_by_id(&self, id: u32) -> Result<&Task, SearchError> {
+        self.tasks
+            .iter()
+            .find(|task| task.id() == Some(id))
+            .ok_or(SearchError::NotFound)
+    }
+}
+
 pub struct TasksBuilder {
     tasks: Vec<Task>,
 }
 
+impl Default for TasksBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl TasksBuilder {
     pub fn new() -> Self {
         Self::empty()
@@ -0,0 +1,74 @@
+use crate::projects_and_tasks::toggl::task::TogglTask;
+use crate::projects_and_tasks::{
+    project::{ProjectWithTasks, ProjectWithTasksBuilder},
+    task::Task,
+    tasks::TasksBuilder,
+};
+use serde::Deserialize;
+
+#[derive(Deserialize, Debug, PartialEq)]
+pub struct TogglProject {
+    pub id: u64,
+    pub name: String,
+}
+
+impl TogglProject {
+    pub fn to_project(&self, tasks: &[TogglTask]) -> ProjectWithTasks {
+        let tasks: Vec<Task> = self
+            .tasks(tasks)
+            .map(|toggl_task| toggl_task.to_task())
+            .collect();
+
+        ProjectWithTasksBuilder::new()
+            .with_optional_id(None)
+            .with_name(self.name.clone())
+            .with_tasks(TasksBuilder::new().with_tasks(tasks).build())
+            .build()
+    }
+
+    pub fn tasks<'a>(&'a self, tasks: &'a [TogglTask]) -> impl Iterator<Item = &'a TogglTask> {
+        tasks.iter().filter(move |task| task.project_id == self.id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::projects_and_tasks::task::TaskBuilder;
+
+    #[test]
+    fn it_creates_project_with_its_tasks() {
+        let toggl_project = TogglProject {
+            id: 3,
+            name: "project".to_string(),
+        };
+        let tasks = vec![
+            TogglTask {
+                name: "task".to_string(),
+                project_id: 3,
+            },
+            TogglTask {
+                name: "other task".to_string(),
+                project_id: 4,
+            },
+        ];
+
+        let project = toggl_project.to_project(&tasks);
+
+        assert_eq!(
+            project,
+            ProjectWithTasksBuilder::new()
+                .with_optional_id(None)
+                .with_name("project".to_string())
+                .with_tasks(
+                    TasksBuilder::new()
+                        .with_tasks(vec![TaskBuilder::new()
+                            .with_optional_id(None)
+                            .with_name("task".to_string())
+                            .build()])
+                        .build()
+                )
+                .build()
+        );
+    }
+}
@@ -0,0 +1,150 @@
+use crate::projects_and_tasks::toggl::{project::TogglProject, task::TogglTask};
+use crate::projects_and_tasks::{
+    lint::{ProjectOutline, TaskOutline},
+    project::ProjectWithTasks,
+    projects::{Projects, ProjectsBuilder},
+};
+use serde::Deserialize;
+use serde_json::Result;
+
+#[derive(Deserialize, Debug, PartialEq)]
+#[serde(untagged)]
+enum TogglExport {
+    ProjectsAndTasks {
+        projects: Vec<TogglProject>,
+        #[serde(default)]
+        tasks: Vec<TogglTask>,
+    },
+    Projects(Vec<TogglProject>),
+}
+
+#[derive(Debug, PartialEq)]
+pub struct TogglProjects {
+    projects: Vec<TogglProject>,
+    tasks: Vec<TogglTask>,
+    prefix: &'static str,
+}
+
+impl TogglProjects {
+    pub fn from(string: &str) -> Result<TogglProjects> {
+        Ok(match serde_json::from_str(string)? {
+            TogglExport::ProjectsAndTasks { projects, tasks } => Self {
+                projects,
+                tasks,
+                prefix: "$.projects",
+            },
+            TogglExport::Projects(projects) => Self {
+                projects,
+                tasks: vec![],
+                prefix: "$",
+            },
+        })
+    }
+
+    pub fn to_projects(self) -> Projects {
+        let projects: Vec<ProjectWithTasks> = self
+            .projects
+            .iter()
+            .map(|toggl_project| toggl_project.to_project(&self.tasks))
+            .collect();
+
+        ProjectsBuilder::new().with_projects(projects).build()
+    }
+
+    pub fn outline(&self) -> Vec<ProjectOutline> {
+        self.projects
+            .iter()
+            .enumerate()
+            .map(|(i, toggl_project)| ProjectOutline {
+                path: format!("{}[{}]", self.prefix, i),
+                id: None,
+                name: toggl_project.name.clone(),
+                tasks: self
+                    .tasks
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, toggl_task)| toggl_task.project_id == toggl_project.id)
+                    .map(|(j, toggl_task)| TaskOutline {
+                        path: format!("$.tasks[{}]", j),
+                        id: None,
+                        name: toggl_task.name.clone(),
+                    })
+                    .collect(),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::projects_and_tasks::{
+        project::ProjectWithTasksBuilder, task::TaskBuilder, tasks::TasksBuilder,
+    };
+
+    #[test]
+    fn it_parses_projects_and_tasks_export_into_project_list() {
+        let toggl_projects = TogglProjects::from(TOGGL_EXPORT).unwrap();
+
+        assert_eq!(
+            toggl_projects.to_projects(),
+            ProjectsBuilder::new()
+                .with_projects(vec![ProjectWithTasksBuilder::new()
+                    .with_optional_id(None)
+                    .with_name("Timeval".to_string())
+                    .with_tasks(
+                        TasksBuilder::new()
+                            .with_tasks(vec![TaskBuilder::new()
+                                .with_optional_id(None)
+                                .with_name("Build".to_string())
+                                .build()])
+                            .build()
+                    )
+                    .build()])
+                .build()
+        );
+    }
+
+    #[test]
+    fn it_parses_projects_export_without_tasks() {
+        let toggl_projects =
+            TogglProjects::from(r#"[{"id": 1, "workspace_id": 2, "name": "Training"}]"#).unwrap();
+
+        assert_eq!(
+            toggl_projects.outline(),
+            vec![ProjectOutline {
+                path: "$[0]".to_string(),
+                id: None,
+                name: "Training".to_string(),
+                tasks: vec![],
+            }]
+        );
+    }
+
+    const TOGGL_EXPORT: &str = r#"
+{
+  "projects": [
+    {
+      "id": 193791284,
+      "workspace_id": 4433221,
+      "client_id": null,
+      "name": "Timeval",
+      "is_private": true,
+      "active": true,
+      "billable": false,
+      "color": "06aaf5"
+    }
+  ],
+  "tasks": [
+    {
+      "id": 79311346,
+      "name": "Build",
+      "workspace_id": 4433221,
+      "project_id": 193791284,
+      "active": true,
+      "estimated_seconds": 0
+    }
+  ]
+}
+"#;
+}
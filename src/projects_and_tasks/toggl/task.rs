@@ -0,0 +1,40 @@
+use crate::projects_and_tasks::task::{Task, TaskBuilder};
+use serde::Deserialize;
+
+#[derive(Deserialize, Debug, PartialEq)]
+pub struct TogglTask {
+    pub name: String,
+    pub project_id: u64,
+}
+
+impl TogglTask {
+    pub fn to_task(&self) -> Task {
+        TaskBuilder::new()
+            .with_optional_id(None)
+            .with_name(self.name.clone())
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_creates_task_without_a_harvest_id() {
+        let toggl_task = TogglTask {
+            name: "task".to_string(),
+            project_id: 3,
+        };
+
+        let task = toggl_task.to_task();
+
+        assert_eq!(
+            task,
+            TaskBuilder::new()
+                .with_optional_id(None)
+                .with_name("task".to_string())
+                .build()
+        );
+    }
+}
@@ -0,0 +1,45 @@
+use crate::rules::format_duration;
+use chrono::Duration;
+use std::fmt;
+
+#[derive(Debug, PartialEq)]
+pub enum BreakError {
+    NoBreak {
+        worked: Duration,
+        limit: Duration,
+    },
+    TooShortBreaks {
+        worked: Duration,
+        breaks: Duration,
+        required: Duration,
+    },
+}
+
+impl fmt::Display for BreakError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let error_name = "BreakError";
+        match &self {
+            Self::NoBreak { worked, limit } => write!(
+                f,
+                "{:<10} | {}: Worked {} hours without a break, at most {} hours allowed",
+                "Breaks",
+                error_name,
+                format_duration(*worked),
+                format_duration(*limit)
+            ),
+            Self::TooShortBreaks {
+                worked,
+                breaks,
+                required,
+            } => write!(
+                f,
+                "{:<10} | {}: Worked {} hours with {} hours of breaks, {} hours required",
+                "Breaks",
+                error_name,
+                format_duration(*worked),
+                format_duration(*breaks),
+                format_duration(*required)
+            ),
+        }
+    }
+}
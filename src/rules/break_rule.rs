@@ -0,0 +1,143 @@
+use crate::config::BreakConfig;
+use crate::line_error::LineError;
+use crate::rules::{break_error::BreakError, days, Interval};
+use crate::validation::{activity::Activity, rule::Rule};
+use chrono::Duration;
+
+pub struct BreakRule {
+    config: BreakConfig,
+}
+
+impl BreakRule {
+    pub fn new(config: BreakConfig) -> Self {
+        Self { config }
+    }
+}
+
+impl Rule for BreakRule {
+    fn check(&self, activities: &[(usize, Activity)]) -> Vec<LineError> {
+        days(activities)
+            .values()
+            .flat_map(|day| {
+                let intervals = day
+                    .iter()
+                    .map(|(_no, activity)| (*activity.start().date(), *activity.end().date()))
+                    .collect::<Vec<_>>();
+                check_day(&intervals, &self.config)
+                    .into_iter()
+                    .map(move |(index, error)| LineError::new(day[index].0, error.to_string()))
+            })
+            .collect()
+    }
+}
+
+pub fn check_day(intervals: &[Interval], config: &BreakConfig) -> Vec<(usize, BreakError)> {
+    let mut errors = vec![];
+    let mut worked = Duration::zero();
+    let mut continuous = Duration::zero();
+    let mut breaks = Duration::zero();
+    let mut reported = false;
+    let mut previous_end = None;
+    for (index, (start, end)) in intervals.iter().enumerate() {
+        if let Some(previous_end) = previous_end {
+            let gap = *start - previous_end;
+            if gap >= config.minimum_break() {
+                breaks = breaks + gap;
+                continuous = Duration::zero();
+                reported = false;
+            }
+        }
+        let duration = *end - *start;
+        worked = worked + duration;
+        continuous = continuous + duration;
+        if continuous > config.max_continuous_work() && !reported {
+            errors.push((
+                index,
+                BreakError::NoBreak {
+                    worked: continuous,
+                    limit: config.max_continuous_work(),
+                },
+            ));
+            reported = true;
+        }
+        previous_end = Some(previous_end.map_or(*end, |previous| std::cmp::max(previous, *end)));
+    }
+
+    let required = config.required_break(worked);
+    if breaks < required {
+        errors.push((
+            intervals.len() - 1,
+            BreakError::TooShortBreaks {
+                worked,
+                breaks,
+                required,
+            },
+        ));
+    }
+    errors
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::prelude::*;
+
+    fn interval(start: (u32, u32), end: (u32, u32)) -> Interval {
+        (
+            Local.ymd(2020, 1, 13).and_hms(start.0, start.1, 0),
+            Local.ymd(2020, 1, 13).and_hms(end.0, end.1, 0),
+        )
+    }
+
+    #[test]
+    fn it_accepts_days_with_enough_breaks() {
+        let intervals = vec![
+            interval((8, 0), (12, 0)),
+            interval((12, 30), (15, 0)),
+            interval((15, 10), (16, 0)),
+        ];
+
+        assert_eq!(check_day(&intervals, &BreakConfig::default()), vec![]);
+    }
+
+    #[test]
+    fn it_reports_too_long_work_without_break() {
+        let intervals = vec![
+            interval((8, 0), (12, 0)),
+            interval((12, 5), (14, 30)),
+            interval((15, 0), (16, 0)),
+        ];
+
+        assert_eq!(
+            check_day(&intervals, &BreakConfig::default()),
+            vec![(
+                1,
+                BreakError::NoBreak {
+                    worked: Duration::minutes(385),
+                    limit: Duration::hours(6)
+                }
+            )]
+        );
+    }
+
+    #[test]
+    fn it_reports_too_short_breaks_at_the_last_entry() {
+        let intervals = vec![
+            interval((7, 0), (12, 0)),
+            interval((12, 30), (17, 0)),
+            interval((17, 10), (17, 25)),
+        ];
+
+        assert_eq!(
+            check_day(&intervals, &BreakConfig::default()),
+            vec![(
+                2,
+                BreakError::TooShortBreaks {
+                    worked: Duration::minutes(585),
+                    breaks: Duration::minutes(30),
+                    required: Duration::minutes(45)
+                }
+            )]
+        );
+    }
+}
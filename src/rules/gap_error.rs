@@ -0,0 +1,33 @@
+use crate::rules::format_duration;
+use chrono::Duration;
+use std::fmt;
+
+#[derive(Debug, PartialEq)]
+pub enum GapError {
+    PossibleBreak { gap: Duration, after: String },
+    Unexplained { gap: Duration, after: String },
+}
+
+impl fmt::Display for GapError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let error_name = "GapError";
+        match &self {
+            Self::PossibleBreak { gap, after } => write!(
+                f,
+                "{:<10} | {}: Gap of {} hours after {}, is this a break?",
+                "Start",
+                error_name,
+                format_duration(*gap),
+                after
+            ),
+            Self::Unexplained { gap, after } => write!(
+                f,
+                "{:<10} | {}: Unexplained gap of {} hours after {}, missing an entry?",
+                "Start",
+                error_name,
+                format_duration(*gap),
+                after
+            ),
+        }
+    }
+}
@@ -0,0 +1,117 @@
+use crate::config::GapConfig;
+use crate::line_error::LineError;
+use crate::rules::{days, gap_error::GapError};
+use crate::validation::{activity::Activity, rule::Rule};
+use chrono::{DateTime, Local, TimeZone};
+use std::cmp::{max, min};
+
+const TIME_FORMAT: &str = "%H:%M";
+
+pub struct GapRule {
+    config: GapConfig,
+}
+
+impl GapRule {
+    pub fn new(config: GapConfig) -> Self {
+        Self { config }
+    }
+
+    fn working_hours(&self, time: &DateTime<Local>) -> (DateTime<Local>, DateTime<Local>) {
+        let date = time.date().naive_local();
+        let at = |time| {
+            Local
+                .from_local_datetime(&date.and_time(time))
+                .earliest()
+                .unwrap_or_else(|| Local.from_utc_datetime(&date.and_time(time)))
+        };
+        (
+            at(self.config.working_hours.start),
+            at(self.config.working_hours.end),
+        )
+    }
+}
+
+impl Rule for GapRule {
+    fn check(&self, activities: &[(usize, Activity)]) -> Vec<LineError> {
+        let mut errors = vec![];
+        for day in days(activities).values() {
+            let mut previous_end: Option<DateTime<Local>> = None;
+            for (no, activity) in day {
+                let start = *activity.start().date();
+                let end = *activity.end().date();
+                if let Some(previous_end) = previous_end {
+                    let (day_start, day_end) = self.working_hours(&start);
+                    let gap = min(start, day_end) - max(previous_end, day_start);
+                    if gap > self.config.minimum_gap() {
+                        let after = previous_end.format(TIME_FORMAT).to_string();
+                        let error = if gap <= self.config.maximum_break() {
+                            GapError::PossibleBreak { gap, after }
+                        } else {
+                            GapError::Unexplained { gap, after }
+                        };
+                        errors.push(LineError::new(*no, error.to_string()));
+                    }
+                }
+                previous_end = Some(previous_end.map_or(end, |previous| max(previous, end)));
+            }
+        }
+        errors
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::{time, ActivityBuilder};
+    use chrono::Duration;
+
+    fn activity(start: (u32, u32), end: (u32, u32)) -> Activity {
+        ActivityBuilder::new()
+            .with_times(time(1, 13, start.0, start.1), time(1, 13, end.0, end.1))
+            .build()
+    }
+
+    #[test]
+    fn it_reports_gaps_within_working_hours() {
+        let rule = GapRule::new(GapConfig::default());
+
+        let errors = rule.check(&[
+            (0, activity((6, 0), (7, 0))),
+            (1, activity((8, 10), (12, 0))),
+            (2, activity((12, 40), (14, 0))),
+            (3, activity((15, 30), (16, 0))),
+            (4, activity((16, 10), (17, 0))),
+            (5, activity((19, 0), (20, 0))),
+        ]);
+
+        assert_eq!(
+            errors,
+            vec![
+                LineError::new(
+                    2,
+                    GapError::PossibleBreak {
+                        gap: Duration::minutes(40),
+                        after: "12:00".to_string()
+                    }
+                    .to_string()
+                ),
+                LineError::new(
+                    3,
+                    GapError::Unexplained {
+                        gap: Duration::minutes(90),
+                        after: "14:00".to_string()
+                    }
+                    .to_string()
+                ),
+                LineError::new(
+                    5,
+                    GapError::PossibleBreak {
+                        gap: Duration::minutes(60),
+                        after: "17:00".to_string()
+                    }
+                    .to_string()
+                ),
+            ]
+        );
+    }
+}
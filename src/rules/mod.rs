@@ -0,0 +1,57 @@
+pub mod break_error;
+pub mod break_rule;
+pub mod gap_error;
+pub mod gap_rule;
+pub mod rest_error;
+pub mod rest_rule;
+pub mod rounding;
+pub mod rounding_error;
+pub mod rounding_rule;
+pub mod weekend_error;
+pub mod weekend_rule;
+pub mod working_time_error;
+pub mod working_time_rule;
+
+use crate::projects_and_tasks::{list_with_names::ListWithNames, named::Named, projects::Projects};
+use crate::validation::activity::Activity;
+use chrono::{DateTime, Duration, Local, NaiveDate};
+use std::collections::BTreeMap;
+
+pub type Interval = (DateTime<Local>, DateTime<Local>);
+
+pub fn format_duration(duration: Duration) -> String {
+    let minutes = duration.num_minutes();
+    let sign = if minutes < 0 { "-" } else { "" };
+    format!("{}{}:{:02}", sign, minutes.abs() / 60, minutes.abs() % 60)
+}
+
+pub fn days(activities: &[(usize, Activity)]) -> BTreeMap<NaiveDate, Vec<&(usize, Activity)>> {
+    let mut days: BTreeMap<NaiveDate, Vec<&(usize, Activity)>> = BTreeMap::new();
+    for activity in activities {
+        days.entry(activity.1.start().date().date().naive_local())
+            .or_default()
+            .push(activity);
+    }
+    for day in days.values_mut() {
+        day.sort_by_key(|(_no, activity)| *activity.start().date());
+    }
+    days
+}
+
+pub fn project_name(projects: &Projects, name: &str) -> String {
+    projects
+        .find(name)
+        .map(|project| project.name().to_string())
+        .unwrap_or_else(|_| name.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_formats_durations_as_hours_and_minutes() {
+        assert_eq!(format_duration(Duration::minutes(390)), "6:30");
+        assert_eq!(format_duration(Duration::minutes(-5)), "-0:05");
+    }
+}
@@ -0,0 +1,33 @@
+use crate::rules::format_duration;
+use chrono::Duration;
+use std::fmt;
+
+#[derive(Debug, PartialEq)]
+pub enum RestError {
+    TooShort {
+        since: String,
+        rest: Duration,
+        minimum: Duration,
+    },
+}
+
+impl fmt::Display for RestError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let error_name = "RestError";
+        match &self {
+            Self::TooShort {
+                since,
+                rest,
+                minimum,
+            } => write!(
+                f,
+                "{:<10} | {}: Rested {} hours since {}, at least {} hours required",
+                "Start",
+                error_name,
+                format_duration(*rest),
+                since,
+                format_duration(*minimum)
+            ),
+        }
+    }
+}
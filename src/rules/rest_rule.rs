@@ -0,0 +1,119 @@
+use crate::config::RestConfig;
+use crate::line_error::LineError;
+use crate::projects_and_tasks::projects::Projects;
+use crate::rules::{days, project_name, rest_error::RestError};
+use crate::validation::{activity::Activity, rule::Rule};
+use chrono::{DateTime, Local};
+
+const DATE_TIME_FORMAT: &str = "%Y-%m-%d %H:%M";
+
+pub struct RestRule {
+    config: RestConfig,
+    exempt_projects: Vec<String>,
+}
+
+impl RestRule {
+    pub fn new(config: RestConfig, projects: &Projects) -> Self {
+        let exempt_projects = config
+            .exempt_projects
+            .iter()
+            .map(|project| project_name(projects, project))
+            .collect();
+        Self {
+            config,
+            exempt_projects,
+        }
+    }
+
+    fn is_exempt(&self, activity: &Activity) -> bool {
+        self.exempt_projects
+            .iter()
+            .any(|project| project.eq_ignore_ascii_case(activity.project().name()))
+    }
+}
+
+impl Rule for RestRule {
+    fn check(&self, activities: &[(usize, Activity)]) -> Vec<LineError> {
+        let mut errors = vec![];
+        let mut previous_end: Option<DateTime<Local>> = None;
+        for day in days(activities).values() {
+            let day = day
+                .iter()
+                .filter(|(_no, activity)| !self.is_exempt(activity))
+                .collect::<Vec<_>>();
+            let (no, first) = match day.first() {
+                Some(first) => first,
+                None => continue,
+            };
+            if let Some(previous_end) = previous_end {
+                let rest = *first.start().date() - previous_end;
+                if rest < self.config.minimum_rest() {
+                    let error = RestError::TooShort {
+                        since: previous_end.format(DATE_TIME_FORMAT).to_string(),
+                        rest,
+                        minimum: self.config.minimum_rest(),
+                    };
+                    errors.push(LineError::new(*no, error.to_string()));
+                }
+            }
+            previous_end = day
+                .iter()
+                .map(|(_no, activity)| *activity.end().date())
+                .max();
+        }
+        errors
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::projects_and_tasks::{project::ProjectWithTasksBuilder, projects::ProjectsBuilder};
+    use crate::test_fixtures::{time, ActivityBuilder};
+    use chrono::Duration;
+
+    fn activity(project: &str, start: (u32, u32), end: (u32, u32)) -> Activity {
+        ActivityBuilder::new()
+            .with_times(time(1, start.0, start.1, 0), time(1, end.0, end.1, 0))
+            .with_project_name(project)
+            .build()
+    }
+
+    #[test]
+    fn it_reports_too_short_rest_but_ignores_exempt_projects() {
+        let projects = ProjectsBuilder::new()
+            .with_projects(vec![ProjectWithTasksBuilder::new()
+                .with_name("On-call duty".to_string())
+                .build()])
+            .build();
+        let rule = RestRule::new(
+            RestConfig {
+                enabled: true,
+                minimum_hours: 11.0,
+                exempt_projects: vec!["on-call".to_string()],
+            },
+            &projects,
+        );
+
+        let errors = rule.check(&[
+            (0, activity("Timeval", (13, 9), (13, 17))),
+            (1, activity("On-call duty", (13, 22), (13, 23))),
+            (2, activity("Timeval", (14, 4), (14, 8))),
+            (3, activity("Timeval", (14, 19), (14, 22))),
+            (4, activity("Timeval", (15, 7), (15, 12))),
+        ]);
+
+        assert_eq!(
+            errors,
+            vec![LineError::new(
+                4,
+                RestError::TooShort {
+                    since: "2020-01-14 22:00".to_string(),
+                    rest: Duration::hours(9),
+                    minimum: Duration::hours(11)
+                }
+                .to_string()
+            )]
+        );
+    }
+}
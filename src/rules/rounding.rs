@@ -0,0 +1,69 @@
+use crate::config::RoundingConfig;
+use crate::projects_and_tasks::projects::Projects;
+use crate::rules::project_name;
+
+pub struct Roundings {
+    roundings: Vec<(Option<String>, RoundingConfig)>,
+}
+
+impl Roundings {
+    pub fn new(configs: &[RoundingConfig], projects: &Projects) -> Self {
+        Self {
+            roundings: configs
+                .iter()
+                .map(|config| {
+                    let project = config
+                        .project
+                        .as_ref()
+                        .map(|project| project_name(projects, project));
+                    (project, config.clone())
+                })
+                .collect(),
+        }
+    }
+
+    pub fn for_project(&self, name: &str) -> Option<&RoundingConfig> {
+        self.roundings
+            .iter()
+            .find(|(project, _config)| {
+                project
+                    .as_ref()
+                    .is_none_or(|project| project.eq_ignore_ascii_case(name))
+            })
+            .map(|(_project, config)| config)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::RoundingDirection;
+    use crate::projects_and_tasks::{project::ProjectWithTasksBuilder, projects::ProjectsBuilder};
+
+    fn rounding(project: Option<&str>, minutes: i64) -> RoundingConfig {
+        RoundingConfig {
+            project: project.map(String::from),
+            minutes,
+            direction: RoundingDirection::Nearest,
+        }
+    }
+
+    #[test]
+    fn it_takes_the_first_rounding_of_a_project() {
+        let projects = ProjectsBuilder::new()
+            .with_projects(vec![ProjectWithTasksBuilder::new()
+                .with_name("My Timeval project".to_string())
+                .build()])
+            .build();
+        let roundings = Roundings::new(
+            &[rounding(Some("timeval"), 15), rounding(None, 30)],
+            &projects,
+        );
+
+        assert_eq!(
+            roundings.for_project("My Timeval project").unwrap().minutes,
+            15
+        );
+        assert_eq!(roundings.for_project("Training").unwrap().minutes, 30);
+    }
+}
@@ -0,0 +1,25 @@
+use std::fmt;
+
+#[derive(Debug, PartialEq)]
+pub enum RoundingError {
+    StartOffGrid { time: String, minutes: i64 },
+    EndOffGrid { time: String, minutes: i64 },
+}
+
+impl fmt::Display for RoundingError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let error_name = "RoundingError";
+        match &self {
+            Self::StartOffGrid { time, minutes } => write!(
+                f,
+                "{:<10} | {}: {} is not on the {} minute grid of the project",
+                "Start", error_name, time, minutes
+            ),
+            Self::EndOffGrid { time, minutes } => write!(
+                f,
+                "{:<10} | {}: {} is not on the {} minute grid of the project",
+                "End", error_name, time, minutes
+            ),
+        }
+    }
+}
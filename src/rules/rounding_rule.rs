@@ -0,0 +1,94 @@
+use crate::line_error::LineError;
+use crate::rules::{rounding::Roundings, rounding_error::RoundingError};
+use crate::validation::{activity::Activity, rule::Rule};
+
+const TIME_FORMAT: &str = "%H:%M:%S";
+
+pub struct RoundingRule {
+    roundings: Roundings,
+}
+
+impl RoundingRule {
+    pub fn new(roundings: Roundings) -> Self {
+        Self { roundings }
+    }
+}
+
+impl Rule for RoundingRule {
+    fn check(&self, activities: &[(usize, Activity)]) -> Vec<LineError> {
+        let mut errors = vec![];
+        for (no, activity) in activities {
+            let rounding = match self.roundings.for_project(activity.project().name()) {
+                Some(rounding) => rounding,
+                None => continue,
+            };
+            let start = activity.start().date();
+            if !rounding.is_on_grid(start) {
+                let error = RoundingError::StartOffGrid {
+                    time: start.format(TIME_FORMAT).to_string(),
+                    minutes: rounding.minutes,
+                };
+                errors.push(LineError::new(*no, error.to_string()));
+            }
+            let end = activity.end().date();
+            if !rounding.is_on_grid(end) {
+                let error = RoundingError::EndOffGrid {
+                    time: end.format(TIME_FORMAT).to_string(),
+                    minutes: rounding.minutes,
+                };
+                errors.push(LineError::new(*no, error.to_string()));
+            }
+        }
+        errors
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{RoundingConfig, RoundingDirection};
+    use crate::projects_and_tasks::{project::ProjectWithTasksBuilder, projects::ProjectsBuilder};
+    use crate::test_fixtures::{time, ActivityBuilder};
+
+    fn activity(project: &str, start: (u32, u32), end: (u32, u32)) -> Activity {
+        ActivityBuilder::new()
+            .with_times(time(1, 12, start.0, start.1), time(1, 12, end.0, end.1))
+            .with_project_name(project)
+            .build()
+    }
+
+    #[test]
+    fn it_flags_times_off_the_grid_of_the_project() {
+        let projects = ProjectsBuilder::new()
+            .with_projects(vec![ProjectWithTasksBuilder::new()
+                .with_name("Timeval".to_string())
+                .build()])
+            .build();
+        let rule = RoundingRule::new(Roundings::new(
+            &[RoundingConfig {
+                project: Some("Timeval".to_string()),
+                minutes: 15,
+                direction: RoundingDirection::Nearest,
+            }],
+            &projects,
+        ));
+
+        let errors = rule.check(&[
+            (0, activity("Timeval", (8, 0), (8, 30))),
+            (1, activity("Timeval", (8, 30), (8, 50))),
+            (2, activity("Training", (8, 50), (9, 0))),
+        ]);
+
+        assert_eq!(
+            errors,
+            vec![LineError::new(
+                1,
+                RoundingError::EndOffGrid {
+                    time: "08:50:00".to_string(),
+                    minutes: 15
+                }
+                .to_string()
+            )]
+        );
+    }
+}
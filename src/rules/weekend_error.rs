@@ -0,0 +1,23 @@
+use std::fmt;
+
+#[derive(Debug, PartialEq)]
+pub enum WeekendError {
+    Weekend(String),
+    Holiday(String),
+}
+
+impl fmt::Display for WeekendError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let error_name = "WeekendError";
+        match &self {
+            Self::Weekend(day) => {
+                write!(f, "{:<10} | {}: Booked on a {}", "Start", error_name, day)
+            }
+            Self::Holiday(name) => write!(
+                f,
+                "{:<10} | {}: Booked on the holiday {}",
+                "Start", error_name, name
+            ),
+        }
+    }
+}
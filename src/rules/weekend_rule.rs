@@ -0,0 +1,94 @@
+use crate::config::WeekendConfig;
+use crate::holidays::holiday_calendar::HolidayCalendar;
+use crate::line_error::LineError;
+use crate::projects_and_tasks::projects::Projects;
+use crate::rules::{project_name, weekend_error::WeekendError};
+use crate::validation::{activity::Activity, rule::Rule};
+use chrono::{Datelike, Weekday};
+
+pub struct WeekendRule {
+    holidays: HolidayCalendar,
+    allowed_projects: Vec<String>,
+}
+
+impl WeekendRule {
+    pub fn new(config: &WeekendConfig, holidays: HolidayCalendar, projects: &Projects) -> Self {
+        Self {
+            holidays,
+            allowed_projects: config
+                .allowed_projects
+                .iter()
+                .map(|project| project_name(projects, project))
+                .collect(),
+        }
+    }
+
+    fn is_allowed(&self, activity: &Activity) -> bool {
+        self.allowed_projects
+            .iter()
+            .any(|project| project.eq_ignore_ascii_case(activity.project().name()))
+    }
+}
+
+impl Rule for WeekendRule {
+    fn check(&self, activities: &[(usize, Activity)]) -> Vec<LineError> {
+        activities
+            .iter()
+            .filter(|(_no, activity)| !self.is_allowed(activity))
+            .filter_map(|(no, activity)| {
+                let date = activity.start().date().date().naive_local();
+                let error = match (self.holidays.name(date), date.weekday()) {
+                    (Some(name), _) => WeekendError::Holiday(name),
+                    (None, Weekday::Sat) => WeekendError::Weekend("Saturday".to_string()),
+                    (None, Weekday::Sun) => WeekendError::Weekend("Sunday".to_string()),
+                    _ => return None,
+                };
+                Some(LineError::new(*no, error.to_string()))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::projects_and_tasks::projects::ProjectsBuilder;
+    use crate::test_fixtures::{time, ActivityBuilder};
+
+    fn activity(project: &str, month: u32, day: u32) -> Activity {
+        ActivityBuilder::new()
+            .with_times(time(month, day, 8, 0), time(month, day, 9, 0))
+            .with_project_name(project)
+            .build()
+    }
+
+    #[test]
+    fn it_reports_bookings_on_weekends_and_holidays_of_other_projects() {
+        let rule = WeekendRule::new(
+            &WeekendConfig {
+                enabled: true,
+                allowed_projects: vec!["On-call".to_string()],
+            },
+            HolidayCalendar::new().with_state("HE".parse().unwrap()),
+            &ProjectsBuilder::empty().build(),
+        );
+
+        let errors = rule.check(&[
+            (0, activity("Timeval", 1, 10)),
+            (1, activity("Timeval", 1, 11)),
+            (2, activity("On-call", 1, 12)),
+            (3, activity("Timeval", 6, 11)),
+        ]);
+
+        assert_eq!(
+            errors,
+            vec![
+                LineError::new(1, WeekendError::Weekend("Saturday".to_string()).to_string()),
+                LineError::new(
+                    3,
+                    WeekendError::Holiday("Fronleichnam".to_string()).to_string()
+                ),
+            ]
+        );
+    }
+}
@@ -0,0 +1,47 @@
+use crate::rules::format_duration;
+use chrono::Duration;
+use std::fmt;
+
+#[derive(Debug, PartialEq)]
+pub enum WorkingTimeError {
+    Daily {
+        day: String,
+        worked: Duration,
+        limit: Duration,
+    },
+    Weekly {
+        week: String,
+        worked: Duration,
+        limit: Duration,
+    },
+}
+
+impl fmt::Display for WorkingTimeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let error_name = "WorkingTimeError";
+        match &self {
+            Self::Daily { day, worked, limit } => write!(
+                f,
+                "{:<10} | {}: Worked {} hours on {}, at most {} hours allowed",
+                "Day",
+                error_name,
+                format_duration(*worked),
+                day,
+                format_duration(*limit)
+            ),
+            Self::Weekly {
+                week,
+                worked,
+                limit,
+            } => write!(
+                f,
+                "{:<10} | {}: Worked {} hours in week {}, at most {} hours allowed",
+                "Week",
+                error_name,
+                format_duration(*worked),
+                week,
+                format_duration(*limit)
+            ),
+        }
+    }
+}
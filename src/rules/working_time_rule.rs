@@ -0,0 +1,132 @@
+use crate::config::WorkingTimeConfig;
+use crate::line_error::LineError;
+use crate::rules::{days, working_time_error::WorkingTimeError};
+use crate::validation::{activity::Activity, rule::Rule};
+use chrono::{Datelike, Duration};
+use std::collections::BTreeMap;
+
+pub struct WorkingTimeRule {
+    config: WorkingTimeConfig,
+}
+
+impl WorkingTimeRule {
+    pub fn new(config: WorkingTimeConfig) -> Self {
+        Self { config }
+    }
+}
+
+impl Rule for WorkingTimeRule {
+    fn check(&self, activities: &[(usize, Activity)]) -> Vec<LineError> {
+        let mut errors = vec![];
+        let mut weeks: BTreeMap<(i32, u32), Vec<&(usize, Activity)>> = BTreeMap::new();
+        for (day, day_activities) in days(activities) {
+            let worked = total(&day_activities);
+            if let Some(line) = crossing(&day_activities, self.config.max_daily()) {
+                let error = WorkingTimeError::Daily {
+                    day: day.to_string(),
+                    worked,
+                    limit: self.config.max_daily(),
+                };
+                errors.push(LineError::new(line, error.to_string()));
+            }
+            let week = day.iso_week();
+            weeks
+                .entry((week.year(), week.week()))
+                .or_default()
+                .extend(day_activities);
+        }
+
+        if let Some(limit) = self.config.max_weekly() {
+            for ((year, week), week_activities) in weeks {
+                let worked = total(&week_activities);
+                if let Some(line) = crossing(&week_activities, limit) {
+                    let error = WorkingTimeError::Weekly {
+                        week: format!("{}-W{:02}", year, week),
+                        worked,
+                        limit,
+                    };
+                    errors.push(LineError::new(line, error.to_string()));
+                }
+            }
+        }
+        errors
+    }
+}
+
+fn total(activities: &[&(usize, Activity)]) -> Duration {
+    activities
+        .iter()
+        .fold(Duration::zero(), |total, (_no, activity)| {
+            total + duration(activity)
+        })
+}
+
+fn crossing(activities: &[&(usize, Activity)], limit: Duration) -> Option<usize> {
+    let mut worked = Duration::zero();
+    activities.iter().find_map(|(no, activity)| {
+        worked = worked + duration(activity);
+        match worked > limit {
+            true => Some(*no),
+            false => None,
+        }
+    })
+}
+
+fn duration(activity: &Activity) -> Duration {
+    *activity.end().date() - *activity.start().date()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::{time, ActivityBuilder};
+
+    fn activity(day: u32, start: u32, end: u32) -> Activity {
+        ActivityBuilder::new()
+            .with_times(time(1, day, start, 0), time(1, day, end, 0))
+            .build()
+    }
+
+    #[test]
+    fn it_reports_too_long_days_and_weeks_at_the_entry_passing_the_limit() {
+        let rule = WorkingTimeRule::new(WorkingTimeConfig {
+            enabled: true,
+            max_daily_hours: 10.0,
+            max_weekly_hours: Some(20.0),
+        });
+
+        let errors = rule.check(&[
+            (0, activity(13, 7, 12)),
+            (1, activity(13, 12, 18)),
+            (2, activity(13, 18, 19)),
+            (3, activity(14, 8, 16)),
+            (4, activity(15, 8, 10)),
+            (5, activity(16, 8, 9)),
+            (6, activity(20, 8, 10)),
+        ]);
+
+        assert_eq!(
+            errors,
+            vec![
+                LineError::new(
+                    1,
+                    WorkingTimeError::Daily {
+                        day: "2020-01-13".to_string(),
+                        worked: Duration::hours(12),
+                        limit: Duration::hours(10)
+                    }
+                    .to_string()
+                ),
+                LineError::new(
+                    4,
+                    WorkingTimeError::Weekly {
+                        week: "2020-W03".to_string(),
+                        worked: Duration::hours(23),
+                        limit: Duration::hours(20)
+                    }
+                    .to_string()
+                ),
+            ]
+        );
+    }
+}
@@ -0,0 +1,85 @@
+use crate::fix::Slot;
+use crate::parser::fields::Fields;
+use crate::projects_and_tasks::{
+    project::{Project, ProjectBuilder},
+    task::{Task, TaskBuilder},
+};
+use crate::validation::{
+    activity::{Activity, Description},
+    time::{End, Start},
+};
+use chrono::prelude::*;
+
+pub fn time(month: u32, day: u32, hour: u32, minute: u32) -> DateTime<Local> {
+    Local.ymd(2020, month, day).and_hms(hour, minute, 0)
+}
+
+pub struct ActivityBuilder {
+    start: DateTime<Local>,
+    end: DateTime<Local>,
+    project: Project,
+    task: Task,
+    description: String,
+}
+
+impl ActivityBuilder {
+    pub fn new() -> Self {
+        Self {
+            start: time(1, 12, 8, 0),
+            end: time(1, 12, 8, 30),
+            project: ProjectBuilder::new().build(),
+            task: TaskBuilder::new().build(),
+            description: "".to_string(),
+        }
+    }
+
+    pub fn with_times(mut self, start: DateTime<Local>, end: DateTime<Local>) -> Self {
+        self.start = start;
+        self.end = end;
+        self
+    }
+
+    pub fn with_project(mut self, project: Project) -> Self {
+        self.project = project;
+        self
+    }
+
+    pub fn with_project_name(self, name: &str) -> Self {
+        self.with_project(ProjectBuilder::new().with_name(name.to_string()).build())
+    }
+
+    pub fn with_task(mut self, task: Task) -> Self {
+        self.task = task;
+        self
+    }
+
+    pub fn with_description(mut self, description: &str) -> Self {
+        self.description = description.to_string();
+        self
+    }
+
+    pub fn build(self) -> Activity {
+        Activity::from(
+            Start::new(self.start),
+            End::new(self.end),
+            self.project,
+            self.task,
+            Description::new(self.description),
+        )
+    }
+}
+
+pub fn slot(line: usize, start: (u32, u32), end: (u32, u32)) -> Slot {
+    let activity = ActivityBuilder::new()
+        .with_times(time(1, 13, start.0, start.1), time(1, 13, end.0, end.1))
+        .with_project_name("Timeval")
+        .build();
+    let fields = Fields {
+        start: "".to_string(),
+        end: "".to_string(),
+        project: "".to_string(),
+        task: "".to_string(),
+        description: "".to_string(),
+    };
+    Slot::new(line, &fields, &activity)
+}
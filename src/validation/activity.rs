@@ -1,5 +1,6 @@
-use crate::projects_and_tasks::{project::Project, task::Task};
+use crate::projects_and_tasks::{named::Named, project::Project, task::Task};
 use crate::validation::time::{End, Start};
+use std::fmt;
 
 #[derive(Debug, PartialEq)]
 pub struct Description {
@@ -10,6 +11,10 @@ impl Description {
     pub fn new(text: String) -> Self {
         Self { text }
     }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
 }
 
 #[derive(Debug, PartialEq)]
@@ -37,4 +42,39 @@ impl Activity {
             description,
         }
     }
+
+    pub fn start(&self) -> &Start {
+        &self.start
+    }
+
+    pub fn end(&self) -> &End {
+        &self.end
+    }
+
+    pub fn project(&self) -> &Project {
+        &self.project
+    }
+
+    pub fn task(&self) -> &Task {
+        &self.task
+    }
+
+    pub fn description(&self) -> &Description {
+        &self.description
+    }
+}
+
+impl fmt::Display for Activity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let format = "%Y-%m-%dT%H:%M:%S";
+        write!(
+            f,
+            "| {} | {} | {} | {} | {} |",
+            self.start.date().format(format),
+            self.end.date().format(format),
+            self.project.name(),
+            self.task.name(),
+            self.description.text()
+        )
+    }
 }
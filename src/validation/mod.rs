@@ -1,2 +1,3 @@
 pub mod activity;
+pub mod rule;
 pub mod time;
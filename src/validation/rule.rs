@@ -0,0 +1,29 @@
+use crate::line_error::LineError;
+use crate::validation::activity::Activity;
+
+pub trait Rule {
+    fn check(&self, activities: &[(usize, Activity)]) -> Vec<LineError>;
+}
+
+#[derive(Default)]
+pub struct Rules {
+    rules: Vec<Box<dyn Rule>>,
+}
+
+impl Rules {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with(mut self, rule: impl Rule + 'static) -> Self {
+        self.rules.push(Box::new(rule));
+        self
+    }
+
+    pub fn check(&self, activities: &[(usize, Activity)]) -> Vec<LineError> {
+        self.rules
+            .iter()
+            .flat_map(|rule| rule.check(activities))
+            .collect()
+    }
+}
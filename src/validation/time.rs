@@ -9,6 +9,10 @@ impl Start {
     pub fn new(date: DateTime<Local>) -> Self {
         Start { date }
     }
+
+    pub fn date(&self) -> &DateTime<Local> {
+        &self.date
+    }
 }
 
 #[derive(Debug, PartialEq, Clone)]
@@ -20,4 +24,8 @@ impl End {
     pub fn new(date: DateTime<Local>) -> Self {
         End { date }
     }
+
+    pub fn date(&self) -> &DateTime<Local> {
+        &self.date
+    }
 }